@@ -3,13 +3,12 @@
 //! This example demonstrates how to implement the `Backend` trait
 //! for a simple in-memory simulator.
 
-use std::collections::HashMap;
 use std::sync::Mutex;
 
 use async_trait::async_trait;
 use hal_contract::{
     Backend, BackendAvailability, Capabilities, Counts, ExecutionResult, HalError, HalResult,
-    JobId, JobStatus, ValidationResult,
+    InMemoryJobStore, JobId, JobMetadata, JobStatus, JobStore, ValidationResult,
 };
 
 /// A simple circuit type for demonstration.
@@ -19,9 +18,12 @@ struct SimpleCircuit {
 }
 
 /// In-memory mock backend.
+///
+/// Job lifecycle is delegated to a [`JobStore`] rather than owned
+/// directly, so swapping in a durable store doesn't touch this impl.
 struct MockBackend {
     capabilities: Capabilities,
-    jobs: Mutex<HashMap<String, (JobStatus, Option<ExecutionResult>)>>,
+    jobs: InMemoryJobStore,
     next_id: Mutex<u64>,
 }
 
@@ -29,7 +31,7 @@ impl MockBackend {
     fn new(num_qubits: u32) -> Self {
         Self {
             capabilities: Capabilities::simulator(num_qubits),
-            jobs: Mutex::new(HashMap::new()),
+            jobs: InMemoryJobStore::new(),
             next_id: Mutex::new(0),
         }
     }
@@ -92,43 +94,46 @@ impl Backend<SimpleCircuit> for MockBackend {
         counts.insert(&all_ones, (shots - shots / 2).into());
 
         let result = ExecutionResult::new(counts, shots).with_execution_time(42);
+        let job_id = JobId::new(id);
 
         self.jobs
-            .lock()
-            .unwrap()
-            .insert(id.clone(), (JobStatus::Completed, Some(result)));
+            .submit_record(
+                job_id.clone(),
+                JobMetadata {
+                    description: Some(circuit.gates.join(",")),
+                    shots: Some(shots),
+                },
+            )
+            .await;
+        self.jobs
+            .update_status(&job_id, JobStatus::Completed, Some(result))
+            .await;
 
-        Ok(JobId::new(id))
+        Ok(job_id)
     }
 
     async fn status(&self, job_id: &JobId) -> HalResult<JobStatus> {
         self.jobs
-            .lock()
-            .unwrap()
-            .get(&job_id.0)
-            .map(|(s, _)| s.clone())
+            .load(job_id)
+            .await
+            .map(|(status, _)| status)
             .ok_or_else(|| HalError::JobNotFound(job_id.0.clone()))
     }
 
     async fn result(&self, job_id: &JobId) -> HalResult<ExecutionResult> {
         self.jobs
-            .lock()
-            .unwrap()
-            .get(&job_id.0)
-            .and_then(|(_, r)| r.clone())
+            .load(job_id)
+            .await
+            .and_then(|(_, result)| result)
             .ok_or_else(|| HalError::JobNotFound(job_id.0.clone()))
     }
 
     async fn cancel(&self, job_id: &JobId) -> HalResult<()> {
-        let mut jobs = self.jobs.lock().unwrap();
-        if let Some((status, _)) = jobs.get_mut(&job_id.0) {
-            if !status.is_terminal() {
-                *status = JobStatus::Cancelled;
-            }
-            Ok(())
-        } else {
-            Err(HalError::JobNotFound(job_id.0.clone()))
-        }
+        self.jobs
+            .cancel_if_not_terminal(job_id)
+            .await
+            .ok_or_else(|| HalError::JobNotFound(job_id.0.clone()))?;
+        Ok(())
     }
 }
 
@@ -34,16 +34,34 @@
 //! | `result()` | async | yes | `HalResult<ExecutionResult>` |
 //! | `cancel()` | async | yes | `HalResult<()>` |
 //! | `wait()` | async | provided | `HalResult<ExecutionResult>` |
+//! | `wait_with_observer()` | async | provided | `HalResult<ExecutionResult>` |
+//! | `result_stream()` | sync, returns a stream | provided | `Stream<Item = HalResult<ResultChunk>>` |
+//! | `drain_result_stream()` | async | provided | `HalResult<ExecutionResult>` |
+//! | `submit_with_retry()` | async | provided | `HalResult<JobId>` |
+//! | `status_stream()` | sync, returns a stream | provided | `Stream<Item = HalResult<JobStatus>>` |
+//! | `submit_batch()` | async | provided | `Vec<HalResult<JobId>>` |
+//! | `wait_batch()` | async | provided | `HalResult<Vec<HalResult<ExecutionResult>>>` |
+//! | `reattach()` | async | provided | `HalResult<JobStatus>` |
 
+use std::pin::Pin;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use futures::stream::{self, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 
 use crate::capability::Capabilities;
 use crate::error::HalResult;
-use crate::job::{JobId, JobStatus};
-use crate::result::ExecutionResult;
+use crate::job::{JobHandle, JobId, JobStatus};
+use crate::result::{Counts, ExecutionResult, ResultChunk};
+use crate::retry::RetryPolicy;
+use crate::wait::{CancelSwitch, WaitOptions};
+
+/// A stream of partial results, as returned by [`Backend::result_stream`].
+pub type ResultStream<'a> = Pin<Box<dyn Stream<Item = HalResult<ResultChunk>> + Send + 'a>>;
+
+/// A stream of status transitions, as returned by [`Backend::status_stream`].
+pub type StatusStream<'a> = Pin<Box<dyn Stream<Item = HalResult<JobStatus>> + Send + 'a>>;
 
 /// Trait for quantum backends.
 ///
@@ -110,33 +128,345 @@ pub trait Backend<C>: Send + Sync {
     /// Cancel a running job.
     async fn cancel(&self, job_id: &JobId) -> HalResult<()>;
 
+    /// Reconnect to a job previously submitted to this backend, from a
+    /// [`JobHandle`] reloaded after a process restart.
+    ///
+    /// Validates `handle.backend_name` matches [`name()`](Backend::name)
+    /// before trusting `handle.job_id` against this backend — a handle
+    /// for a different backend returns `HalError::JobNotFound` rather
+    /// than silently polling the wrong one. Once reattached, resume
+    /// `status()`/`wait()`/`result()` calls against `handle.job_id`
+    /// exactly as if the process had never restarted.
+    async fn reattach(&self, handle: &JobHandle) -> HalResult<JobStatus> {
+        if handle.backend_name != self.name() {
+            return Err(crate::error::HalError::JobNotFound(format!(
+                "handle belongs to backend '{}', not '{}'",
+                handle.backend_name,
+                self.name()
+            )));
+        }
+        self.status(&handle.job_id).await
+    }
+
     /// Wait for a job to complete and return its result.
     ///
-    /// Default implementation polls every 500ms for up to 5 minutes.
+    /// Thin wrapper over [`wait_with`](Backend::wait_with) using the
+    /// default [`WaitOptions`] (500ms poll, 5-minute timeout) and a
+    /// switch that is never triggered.
     async fn wait(&self, job_id: &JobId) -> HalResult<ExecutionResult> {
+        self.wait_with(job_id, &WaitOptions::default(), &CancelSwitch::new()).await
+    }
+
+    /// Wait for a job to complete, pollable on `options.schedule` and
+    /// cooperatively cancellable via `cancel`.
+    ///
+    /// On each poll iteration, `cancel` is checked before sleeping; once
+    /// triggered, `self.cancel(job_id)` is called to abort the remote job
+    /// (best-effort — its result is not propagated) and this returns
+    /// `Err(HalError::JobCancelled)`. This lets an orchestrator cancel a
+    /// whole fan-out of waits on first failure without dropping the
+    /// futures outright and losing track of the in-flight `JobId`s.
+    ///
+    /// If `availability()` reports `estimated_wait_secs`, the sleep after
+    /// the *first* poll is clamped toward that estimate instead of
+    /// `options.schedule`'s usual first-attempt delay — this avoids
+    /// hammering a status endpoint while a job sits in a long queue.
+    async fn wait_with(
+        &self,
+        job_id: &JobId,
+        options: &WaitOptions,
+        cancel: &CancelSwitch,
+    ) -> HalResult<ExecutionResult> {
+        self.wait_with_observer(job_id, options, cancel, |_| {}).await
+    }
+
+    /// Like [`wait_with`](Backend::wait_with), but calls `on_event` with a
+    /// [`PollEvent`](crate::wait::PollEvent) after every poll — `Polled`
+    /// unconditionally, plus `Warning` whenever that poll or the
+    /// cumulative wait crosses `options`' slow-poll/slow-cumulative
+    /// thresholds — so a caller can log or emit metrics per attempt
+    /// instead of only learning the final outcome.
+    ///
+    /// `on_event` is called synchronously from the polling loop and MUST
+    /// NOT block. Each `PollEvent::Polled` carries `BackendAvailability::queue_depth`
+    /// as of that poll (via a fresh `availability()` call), so a caller can
+    /// chart queue drain instead of only status transitions.
+    ///
+    /// Built on the same [`poll_loop`](crate::wait::poll_loop) that backs
+    /// the free-function [`wait_for_terminal`](crate::wait::wait_for_terminal) —
+    /// cancellation is folded into the status check passed to it, so there
+    /// is still only one polling implementation to keep in sync.
+    async fn wait_with_observer(
+        &self,
+        job_id: &JobId,
+        options: &WaitOptions,
+        cancel: &CancelSwitch,
+        mut on_event: impl FnMut(crate::wait::PollEvent) + Send,
+    ) -> HalResult<ExecutionResult> {
+        use crate::error::HalError;
+
+        let estimated_wait = self
+            .availability()
+            .await
+            .ok()
+            .and_then(|avail| avail.estimated_wait_secs)
+            .map(|secs| Duration::from_secs_f64(secs.max(0.0)).min(options.schedule.upper_bound()));
+
+        let result = crate::wait::poll_loop(
+            job_id,
+            options,
+            || async {
+                if cancel.is_cancelled() {
+                    return Err(HalError::JobCancelled);
+                }
+                self.status(job_id).await
+            },
+            || self.result(job_id),
+            &mut on_event,
+            estimated_wait,
+            || async { self.availability().await.ok().and_then(|avail| avail.queue_depth) },
+        )
+        .await;
+
+        if cancel.is_cancelled() {
+            let _ = self.cancel(job_id).await;
+        }
+
+        result
+    }
+
+    /// Stream partial results as they arrive, for long-running jobs with
+    /// large shot counts that would otherwise only surface via `result()`
+    /// once `Completed` — enabling progress bars and early-stopping logic.
+    ///
+    /// Default implementation yields a single `Unsupported` error;
+    /// backends that can deliver partial results SHOULD override this.
+    fn result_stream<'a>(&'a self, job_id: &'a JobId) -> ResultStream<'a> {
+        let _ = job_id;
+        Box::pin(stream::once(async {
+            Err(crate::error::HalError::Unsupported(
+                "result_stream is not implemented for this backend".to_string(),
+            ))
+        }))
+    }
+
+    /// Fold [`result_stream`](Backend::result_stream)'s chunks into a
+    /// single [`ExecutionResult`], as a convenience for backends that
+    /// implement `result_stream` but want `result()` defined in terms of
+    /// it rather than duplicating the accumulation logic.
+    async fn drain_result_stream(&self, job_id: &JobId) -> HalResult<ExecutionResult> {
+        let mut stream = self.result_stream(job_id);
+        let mut counts = Counts::new();
+        let mut shots_so_far = 0;
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            counts.merge(&chunk.counts);
+            shots_so_far = chunk.shots_so_far;
+        }
+
+        Ok(ExecutionResult::new(counts, shots_so_far))
+    }
+
+    /// Submit a circuit, retrying on [transient](crate::error::HalError::is_transient)
+    /// `submit()` failures per `policy`'s [`RetryPolicy::max_retries`] and
+    /// [`RetryPolicy::backoff`].
+    ///
+    /// Before the first submission and before each retry, this checks
+    /// `availability().is_available`; while the backend reports itself
+    /// offline, it sleeps on the same backoff schedule and checks again
+    /// rather than spending a submit attempt. An `availability()` error
+    /// is treated as "available" — a flaky liveness check should not
+    /// block submission outright.
+    ///
+    /// Waiting out unavailability draws from the same `policy.max_retries`
+    /// budget as submit retries, so a backend that never reports itself
+    /// available returns `HalError::BackendUnavailable` once the budget is
+    /// exhausted instead of waiting forever.
+    async fn submit_with_retry(&self, circuit: &C, shots: u32, policy: &RetryPolicy) -> HalResult<JobId>
+    where
+        C: Sync,
+    {
+        use crate::error::HalError;
+
+        let mut retries_done: u32 = 0;
+        loop {
+            while matches!(self.availability().await, Ok(avail) if !avail.is_available) {
+                if !policy.max_retries.allows(retries_done + 1) {
+                    return Err(HalError::BackendUnavailable(
+                        "backend did not become available within the retry budget".to_string(),
+                    ));
+                }
+                retries_done += 1;
+                tokio::time::sleep(policy.delay_for_attempt(retries_done)).await;
+            }
+
+            match self.submit(circuit, shots).await {
+                Ok(job_id) => return Ok(job_id),
+                Err(err) if err.is_transient() && policy.max_retries.allows(retries_done + 1) => {
+                    retries_done += 1;
+                    tokio::time::sleep(policy.delay_for_attempt(retries_done)).await;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Stream status transitions for a job, so a caller can observe
+    /// `Queued → Running → Completed`-style progress without hand-rolling
+    /// a `status()` poll loop.
+    ///
+    /// Polls on `options.schedule` like [`wait_with`](Backend::wait_with),
+    /// but only yields an item when the status differs from the last one
+    /// observed (so a long `Running` stretch doesn't produce a flood of
+    /// identical items). The stream ends after yielding a terminal status,
+    /// a `status()` error, or `HalError::Timeout` once `options.timeout`
+    /// elapses — whichever comes first.
+    fn status_stream<'a>(&'a self, job_id: &'a JobId, options: &'a WaitOptions) -> StatusStream<'a> {
+        use crate::error::HalError;
+        use crate::wait::apply_jitter;
+        use std::time::Instant;
+
+        struct State {
+            attempt: u32,
+            start: Instant,
+            last: Option<JobStatus>,
+            done: bool,
+        }
+
+        let state = State { attempt: 1, start: Instant::now(), last: None, done: false };
+
+        Box::pin(stream::unfold(state, move |mut state| async move {
+            loop {
+                if state.done {
+                    return None;
+                }
+
+                let status = match self.status(job_id).await {
+                    Ok(status) => status,
+                    Err(err) => {
+                        state.done = true;
+                        return Some((Err(err), state));
+                    }
+                };
+
+                if status.is_terminal() {
+                    state.done = true;
+                    return Some((Ok(status), state));
+                }
+
+                if state.start.elapsed() >= options.timeout {
+                    state.done = true;
+                    return Some((Err(HalError::Timeout(job_id.0.clone())), state));
+                }
+
+                let changed = state.last.as_ref() != Some(&status);
+                if changed {
+                    state.last = Some(status.clone());
+                    state.attempt += 1;
+                    return Some((Ok(status), state));
+                }
+
+                let delay = apply_jitter(options.schedule.delay_for_attempt(state.attempt), options.jitter);
+                state.attempt += 1;
+                tokio::time::sleep(delay).await;
+            }
+        }))
+    }
+
+    /// Submit every circuit in `circuits` against this backend with the
+    /// same `shots`, concurrently, for fanning a parameter sweep or
+    /// circuit family across a backend without a caller-side join loop.
+    ///
+    /// Each circuit's submission is independent — one circuit failing
+    /// `submit()` does not prevent the others from going through. Results
+    /// are returned in the same order as `circuits`; pair this with
+    /// `submit_with_retry` per-circuit if transient failures should be
+    /// retried.
+    async fn submit_batch(&self, circuits: &[C], shots: u32) -> Vec<HalResult<JobId>>
+    where
+        C: Sync,
+    {
+        futures::future::join_all(circuits.iter().map(|circuit| self.submit(circuit, shots))).await
+    }
+
+    /// Wait on every job in `job_ids` concurrently — the natural
+    /// counterpart to [`submit_batch`](Backend::submit_batch) — instead of
+    /// a caller awaiting `wait()` on each one in turn and paying the sum of
+    /// their individual wait times.
+    ///
+    /// Polls all jobs via a single `FuturesUnordered`, so a slow job
+    /// doesn't hold up checking on a fast one. Results are returned in the
+    /// same order as `job_ids`, regardless of which job actually finished
+    /// waiting first.
+    ///
+    /// `mode` controls what happens when one job's wait fails:
+    /// - `BatchWaitMode::FailFast` drops the remaining in-flight waits and
+    ///   returns that job's error as soon as it occurs.
+    /// - `BatchWaitMode::CollectAll` waits out every job regardless of
+    ///   others' failures, reporting each job's own success or failure in
+    ///   its slot.
+    async fn wait_batch(
+        &self,
+        job_ids: &[JobId],
+        options: &WaitOptions,
+        mode: BatchWaitMode,
+    ) -> HalResult<Vec<HalResult<ExecutionResult>>> {
         use crate::error::HalError;
-        use tokio::time::sleep;
+        use futures::stream::FuturesUnordered;
 
-        let poll_interval = Duration::from_millis(500);
-        let max_polls = 600; // 5 minutes max
+        let mut pending: FuturesUnordered<_> = job_ids
+            .iter()
+            .enumerate()
+            .map(|(index, job_id)| async move {
+                (index, self.wait_with(job_id, options, &CancelSwitch::new()).await)
+            })
+            .collect();
 
-        for _ in 0..max_polls {
-            let status = self.status(job_id).await?;
+        let mut slots: Vec<Option<HalResult<ExecutionResult>>> =
+            std::iter::repeat_with(|| None).take(job_ids.len()).collect();
 
-            match status {
-                JobStatus::Completed => return self.result(job_id).await,
-                JobStatus::Failed(msg) => return Err(HalError::JobFailed(msg)),
-                JobStatus::Cancelled => return Err(HalError::JobCancelled),
-                JobStatus::Queued | JobStatus::Running => {
-                    sleep(poll_interval).await;
+        while let Some((index, result)) = pending.next().await {
+            let failed = result.is_err();
+            slots[index] = Some(result);
+            if failed && mode == BatchWaitMode::FailFast {
+                break;
+            }
+        }
+
+        if mode == BatchWaitMode::FailFast {
+            if let Some(failed_index) = slots.iter().position(|slot| matches!(slot, Some(Err(_)))) {
+                let still_pending: Vec<usize> =
+                    (0..slots.len()).filter(|&index| index != failed_index && slots[index].is_none()).collect();
+                for index in still_pending {
+                    let _ = self.cancel(&job_ids[index]).await;
                 }
+                return Err(slots[failed_index].take().unwrap().unwrap_err());
             }
         }
 
-        Err(HalError::Timeout(job_id.0.clone()))
+        Ok(slots
+            .into_iter()
+            .map(|slot| {
+                slot.unwrap_or_else(|| {
+                    Err(HalError::Timeout(
+                        "wait_batch: aborted before this job finished waiting (fail-fast mode)".to_string(),
+                    ))
+                })
+            })
+            .collect())
     }
 }
 
+/// Modes for [`Backend::wait_batch`] when one job in the batch fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchWaitMode {
+    /// Abandon the rest of the batch and return the first failure.
+    FailFast,
+    /// Wait out every job regardless of others' failures.
+    CollectAll,
+}
+
 /// Backend availability information.
 ///
 /// Provides richer availability data than a simple boolean, enabling
@@ -213,6 +543,330 @@ impl ValidationResult {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::HalError;
+
+    struct StubBackend {
+        capabilities: Capabilities,
+    }
+
+    #[async_trait]
+    impl Backend<()> for StubBackend {
+        fn name(&self) -> &str {
+            "stub"
+        }
+
+        fn capabilities(&self) -> &Capabilities {
+            &self.capabilities
+        }
+
+        async fn availability(&self) -> HalResult<BackendAvailability> {
+            Ok(BackendAvailability::always_available())
+        }
+
+        async fn validate(&self, _circuit: &()) -> HalResult<ValidationResult> {
+            Ok(ValidationResult::Valid)
+        }
+
+        async fn submit(&self, _circuit: &(), _shots: u32) -> HalResult<JobId> {
+            Ok(JobId::new("stub-1"))
+        }
+
+        async fn status(&self, _job_id: &JobId) -> HalResult<JobStatus> {
+            Ok(JobStatus::Completed)
+        }
+
+        async fn result(&self, _job_id: &JobId) -> HalResult<ExecutionResult> {
+            Ok(ExecutionResult::default())
+        }
+
+        async fn cancel(&self, _job_id: &JobId) -> HalResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_result_stream_default_is_unsupported() {
+        let backend = StubBackend {
+            capabilities: Capabilities::simulator(1),
+        };
+        let job_id = JobId::new("stub-1");
+        let mut stream = backend.result_stream(&job_id);
+        let first = stream.next().await.unwrap();
+        assert!(matches!(first, Err(HalError::Unsupported(_))));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_drain_result_stream_propagates_unsupported() {
+        let backend = StubBackend {
+            capabilities: Capabilities::simulator(1),
+        };
+        let job_id = JobId::new("stub-1");
+        let result = backend.drain_result_stream(&job_id).await;
+        assert!(matches!(result, Err(HalError::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reattach_resumes_status_for_matching_backend() {
+        let backend = StubBackend {
+            capabilities: Capabilities::simulator(1),
+        };
+        let handle = crate::job::JobHandle::new(JobId::new("stub-1"), "stub", 1_700_000_000, 100);
+
+        let status = backend.reattach(&handle).await.unwrap();
+        assert_eq!(status, JobStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_reattach_rejects_handle_for_another_backend() {
+        let backend = StubBackend {
+            capabilities: Capabilities::simulator(1),
+        };
+        let handle = crate::job::JobHandle::new(JobId::new("stub-1"), "someone-else", 1_700_000_000, 100);
+
+        let result = backend.reattach(&handle).await;
+        assert!(matches!(result, Err(HalError::JobNotFound(_))));
+    }
+
+    struct PendingBackend {
+        capabilities: Capabilities,
+        cancelled: std::sync::atomic::AtomicBool,
+    }
+
+    #[async_trait]
+    impl Backend<()> for PendingBackend {
+        fn name(&self) -> &str {
+            "pending"
+        }
+
+        fn capabilities(&self) -> &Capabilities {
+            &self.capabilities
+        }
+
+        async fn availability(&self) -> HalResult<BackendAvailability> {
+            Ok(BackendAvailability::always_available())
+        }
+
+        async fn validate(&self, _circuit: &()) -> HalResult<ValidationResult> {
+            Ok(ValidationResult::Valid)
+        }
+
+        async fn submit(&self, _circuit: &(), _shots: u32) -> HalResult<JobId> {
+            Ok(JobId::new("pending-1"))
+        }
+
+        async fn status(&self, _job_id: &JobId) -> HalResult<JobStatus> {
+            Ok(JobStatus::Running)
+        }
+
+        async fn result(&self, _job_id: &JobId) -> HalResult<ExecutionResult> {
+            Ok(ExecutionResult::default())
+        }
+
+        async fn cancel(&self, _job_id: &JobId) -> HalResult<()> {
+            self.cancelled.store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_with_times_out() {
+        let backend = PendingBackend {
+            capabilities: Capabilities::simulator(1),
+            cancelled: std::sync::atomic::AtomicBool::new(false),
+        };
+        let options = WaitOptions::new(
+            crate::wait::PollSchedule::Fixed(std::time::Duration::from_millis(1)),
+            std::time::Duration::from_millis(5),
+        );
+        let result = backend
+            .wait_with(&JobId::new("pending-1"), &options, &CancelSwitch::new())
+            .await;
+        assert!(matches!(result, Err(HalError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_wait_with_cancels_on_triggered_switch() {
+        let backend = PendingBackend {
+            capabilities: Capabilities::simulator(1),
+            cancelled: std::sync::atomic::AtomicBool::new(false),
+        };
+        let cancel = CancelSwitch::new();
+        cancel.trigger();
+
+        let result = backend
+            .wait_with(&JobId::new("pending-1"), &WaitOptions::default(), &cancel)
+            .await;
+
+        assert!(matches!(result, Err(HalError::JobCancelled)));
+        assert!(backend.cancelled.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    struct EstimatedWaitBackend {
+        capabilities: Capabilities,
+        polls: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait]
+    impl Backend<()> for EstimatedWaitBackend {
+        fn name(&self) -> &str {
+            "estimated-wait"
+        }
+
+        fn capabilities(&self) -> &Capabilities {
+            &self.capabilities
+        }
+
+        async fn availability(&self) -> HalResult<BackendAvailability> {
+            Ok(BackendAvailability {
+                is_available: true,
+                queue_depth: Some(1),
+                estimated_wait_secs: Some(0.001),
+                status_message: None,
+            })
+        }
+
+        async fn validate(&self, _circuit: &()) -> HalResult<ValidationResult> {
+            Ok(ValidationResult::Valid)
+        }
+
+        async fn submit(&self, _circuit: &(), _shots: u32) -> HalResult<JobId> {
+            Ok(JobId::new("estimated-1"))
+        }
+
+        async fn status(&self, _job_id: &JobId) -> HalResult<JobStatus> {
+            if self.polls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) < 1 {
+                Ok(JobStatus::Running)
+            } else {
+                Ok(JobStatus::Completed)
+            }
+        }
+
+        async fn result(&self, _job_id: &JobId) -> HalResult<ExecutionResult> {
+            Ok(ExecutionResult::default())
+        }
+
+        async fn cancel(&self, _job_id: &JobId) -> HalResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_with_clamps_first_poll_toward_estimated_wait() {
+        let backend = EstimatedWaitBackend {
+            capabilities: Capabilities::simulator(1),
+            polls: std::sync::atomic::AtomicU32::new(0),
+        };
+        // The schedule's own first-attempt delay (10s) would blow well past
+        // this test's 200ms budget; the 1ms `estimated_wait_secs` hint should
+        // be used instead.
+        let options = WaitOptions::new(
+            crate::wait::PollSchedule::Fixed(std::time::Duration::from_secs(10)),
+            std::time::Duration::from_secs(5),
+        );
+
+        let outcome = tokio::time::timeout(
+            std::time::Duration::from_millis(200),
+            backend.wait_with(&JobId::new("estimated-1"), &options, &CancelSwitch::new()),
+        )
+        .await;
+
+        assert!(outcome.is_ok(), "wait_with should use the estimated-wait hint, not the 10s schedule");
+        assert!(outcome.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wait_with_observer_emits_a_polled_event_per_attempt() {
+        let backend = EstimatedWaitBackend {
+            capabilities: Capabilities::simulator(1),
+            polls: std::sync::atomic::AtomicU32::new(0),
+        };
+        let options = WaitOptions::new(
+            crate::wait::PollSchedule::Fixed(std::time::Duration::from_millis(1)),
+            std::time::Duration::from_secs(5),
+        );
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let result = backend
+            .wait_with_observer(&JobId::new("estimated-1"), &options, &CancelSwitch::new(), |event| {
+                events.lock().unwrap().push(event);
+            })
+            .await;
+
+        assert!(result.is_ok());
+        let events = events.lock().unwrap();
+        let polled: Vec<_> = events
+            .iter()
+            .filter(|e| matches!(e, crate::wait::PollEvent::Polled { .. }))
+            .collect();
+        assert_eq!(polled.len(), 2, "EstimatedWaitBackend completes on its second status() poll");
+        assert!(matches!(
+            polled[0],
+            crate::wait::PollEvent::Polled { attempt: 1, status: JobStatus::Running, .. }
+        ));
+        assert!(matches!(
+            polled[1],
+            crate::wait::PollEvent::Polled { attempt: 2, status: JobStatus::Completed, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_with_observer_attaches_queue_depth_from_availability() {
+        let backend = EstimatedWaitBackend {
+            capabilities: Capabilities::simulator(1),
+            polls: std::sync::atomic::AtomicU32::new(0),
+        };
+        let options = WaitOptions::new(
+            crate::wait::PollSchedule::Fixed(std::time::Duration::from_millis(1)),
+            std::time::Duration::from_secs(5),
+        );
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        backend
+            .wait_with_observer(&JobId::new("estimated-1"), &options, &CancelSwitch::new(), |event| {
+                events.lock().unwrap().push(event);
+            })
+            .await
+            .unwrap();
+
+        let events = events.lock().unwrap();
+        let polled: Vec<_> = events
+            .iter()
+            .filter_map(|e| match e {
+                crate::wait::PollEvent::Polled { queue_depth, .. } => Some(*queue_depth),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(polled, vec![Some(1), Some(1)]);
+    }
+
+    #[tokio::test]
+    async fn test_wait_with_observer_emits_slow_cumulative_warning() {
+        let backend = PendingBackend {
+            capabilities: Capabilities::simulator(1),
+            cancelled: std::sync::atomic::AtomicBool::new(false),
+        };
+        let options = WaitOptions::new(
+            crate::wait::PollSchedule::Fixed(std::time::Duration::from_millis(1)),
+            std::time::Duration::from_secs(5),
+        )
+        .with_slow_cumulative_threshold(std::time::Duration::ZERO);
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let _ = tokio::time::timeout(
+            std::time::Duration::from_millis(20),
+            backend.wait_with_observer(&JobId::new("pending-1"), &options, &CancelSwitch::new(), |event| {
+                events.lock().unwrap().push(event);
+            }),
+        )
+        .await;
+
+        let events = events.lock().unwrap();
+        assert!(events.iter().any(|e| matches!(
+            e,
+            crate::wait::PollEvent::Warning(crate::wait::WaitWarning::SlowCumulative(_))
+        )));
+    }
 
     #[test]
     fn test_backend_availability_always_available() {
@@ -229,6 +883,490 @@ mod tests {
         assert_eq!(avail.status_message, Some("maintenance".to_string()));
     }
 
+    struct FlakySubmitBackend {
+        capabilities: Capabilities,
+        available_after: u32,
+        fails_before_success: u32,
+        polls: std::sync::atomic::AtomicU32,
+        attempts: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait]
+    impl Backend<()> for FlakySubmitBackend {
+        fn name(&self) -> &str {
+            "flaky-submit"
+        }
+
+        fn capabilities(&self) -> &Capabilities {
+            &self.capabilities
+        }
+
+        async fn availability(&self) -> HalResult<BackendAvailability> {
+            let poll = self.polls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if poll < self.available_after {
+                Ok(BackendAvailability::unavailable("warming up"))
+            } else {
+                Ok(BackendAvailability::always_available())
+            }
+        }
+
+        async fn validate(&self, _circuit: &()) -> HalResult<ValidationResult> {
+            Ok(ValidationResult::Valid)
+        }
+
+        async fn submit(&self, _circuit: &(), _shots: u32) -> HalResult<JobId> {
+            if self.attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst) < self.fails_before_success {
+                Err(HalError::BackendUnavailable("rate limited".into()))
+            } else {
+                Ok(JobId::new("flaky-1"))
+            }
+        }
+
+        async fn status(&self, _job_id: &JobId) -> HalResult<JobStatus> {
+            Ok(JobStatus::Completed)
+        }
+
+        async fn result(&self, _job_id: &JobId) -> HalResult<ExecutionResult> {
+            Ok(ExecutionResult::default())
+        }
+
+        async fn cancel(&self, _job_id: &JobId) -> HalResult<()> {
+            Ok(())
+        }
+    }
+
+    fn fast_retry_policy() -> RetryPolicy {
+        RetryPolicy::with_backoff(
+            crate::retry::MaxRetries::Count(5),
+            crate::retry::Backoff::Linear { base: std::time::Duration::from_millis(1), max: std::time::Duration::from_millis(1) },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_submit_with_retry_succeeds_after_transient_failures() {
+        let backend = FlakySubmitBackend {
+            capabilities: Capabilities::simulator(1),
+            available_after: 0,
+            fails_before_success: 2,
+            polls: std::sync::atomic::AtomicU32::new(0),
+            attempts: std::sync::atomic::AtomicU32::new(0),
+        };
+
+        let job_id = backend.submit_with_retry(&(), 100, &fast_retry_policy()).await.unwrap();
+        assert_eq!(job_id.0, "flaky-1");
+        assert_eq!(backend.attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    struct InvalidCircuitBackend {
+        capabilities: Capabilities,
+        attempts: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait]
+    impl Backend<()> for InvalidCircuitBackend {
+        fn name(&self) -> &str {
+            "invalid-circuit"
+        }
+
+        fn capabilities(&self) -> &Capabilities {
+            &self.capabilities
+        }
+
+        async fn availability(&self) -> HalResult<BackendAvailability> {
+            Ok(BackendAvailability::always_available())
+        }
+
+        async fn validate(&self, _circuit: &()) -> HalResult<ValidationResult> {
+            Ok(ValidationResult::Invalid { reasons: vec!["too many qubits".into()] })
+        }
+
+        async fn submit(&self, _circuit: &(), _shots: u32) -> HalResult<JobId> {
+            self.attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Err(HalError::InvalidCircuit("too many qubits".into()))
+        }
+
+        async fn status(&self, _job_id: &JobId) -> HalResult<JobStatus> {
+            Ok(JobStatus::Completed)
+        }
+
+        async fn result(&self, _job_id: &JobId) -> HalResult<ExecutionResult> {
+            Ok(ExecutionResult::default())
+        }
+
+        async fn cancel(&self, _job_id: &JobId) -> HalResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_with_retry_stops_on_non_transient() {
+        let backend = InvalidCircuitBackend {
+            capabilities: Capabilities::simulator(1),
+            attempts: std::sync::atomic::AtomicU32::new(0),
+        };
+
+        let result = backend.submit_with_retry(&(), 100, &fast_retry_policy()).await;
+        assert!(matches!(result, Err(HalError::InvalidCircuit(_))));
+        assert_eq!(backend.attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_submit_with_retry_waits_out_unavailability() {
+        let backend = FlakySubmitBackend {
+            capabilities: Capabilities::simulator(1),
+            available_after: 3,
+            fails_before_success: 0,
+            polls: std::sync::atomic::AtomicU32::new(0),
+            attempts: std::sync::atomic::AtomicU32::new(0),
+        };
+
+        let job_id = backend.submit_with_retry(&(), 100, &fast_retry_policy()).await.unwrap();
+        assert_eq!(job_id.0, "flaky-1");
+        assert_eq!(backend.attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(backend.polls.load(std::sync::atomic::Ordering::SeqCst) >= 4);
+    }
+
+    #[tokio::test]
+    async fn test_submit_with_retry_gives_up_on_permanently_unavailable_backend() {
+        let backend = FlakySubmitBackend {
+            capabilities: Capabilities::simulator(1),
+            available_after: u32::MAX,
+            fails_before_success: 0,
+            polls: std::sync::atomic::AtomicU32::new(0),
+            attempts: std::sync::atomic::AtomicU32::new(0),
+        };
+
+        let result = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            backend.submit_with_retry(&(), 100, &fast_retry_policy()),
+        )
+        .await
+        .expect("submit_with_retry must give up instead of waiting on availability forever");
+
+        assert!(matches!(result, Err(HalError::BackendUnavailable(_))));
+        assert_eq!(backend.attempts.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+
+    struct TransitioningBackend {
+        capabilities: Capabilities,
+        statuses: Vec<JobStatus>,
+        polls: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait]
+    impl Backend<()> for TransitioningBackend {
+        fn name(&self) -> &str {
+            "transitioning"
+        }
+
+        fn capabilities(&self) -> &Capabilities {
+            &self.capabilities
+        }
+
+        async fn availability(&self) -> HalResult<BackendAvailability> {
+            Ok(BackendAvailability::always_available())
+        }
+
+        async fn validate(&self, _circuit: &()) -> HalResult<ValidationResult> {
+            Ok(ValidationResult::Valid)
+        }
+
+        async fn submit(&self, _circuit: &(), _shots: u32) -> HalResult<JobId> {
+            Ok(JobId::new("transitioning-1"))
+        }
+
+        async fn status(&self, _job_id: &JobId) -> HalResult<JobStatus> {
+            let poll = self.polls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) as usize;
+            Ok(self.statuses[poll.min(self.statuses.len() - 1)].clone())
+        }
+
+        async fn result(&self, _job_id: &JobId) -> HalResult<ExecutionResult> {
+            Ok(ExecutionResult::default())
+        }
+
+        async fn cancel(&self, _job_id: &JobId) -> HalResult<()> {
+            Ok(())
+        }
+    }
+
+    fn fast_wait_options() -> WaitOptions {
+        WaitOptions::new(
+            crate::wait::PollSchedule::Fixed(std::time::Duration::from_millis(1)),
+            std::time::Duration::from_secs(5),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_status_stream_yields_only_on_transition() {
+        let backend = TransitioningBackend {
+            capabilities: Capabilities::simulator(1),
+            statuses: vec![
+                JobStatus::Queued,
+                JobStatus::Queued,
+                JobStatus::Running,
+                JobStatus::Running,
+                JobStatus::Completed,
+            ],
+            polls: std::sync::atomic::AtomicU32::new(0),
+        };
+
+        let job_id = JobId::new("transitioning-1");
+        let items: Vec<_> = backend
+            .status_stream(&job_id, &fast_wait_options())
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(items, vec![JobStatus::Queued, JobStatus::Running, JobStatus::Completed]);
+    }
+
+    #[tokio::test]
+    async fn test_status_stream_ends_after_terminal_status() {
+        let backend = TransitioningBackend {
+            capabilities: Capabilities::simulator(1),
+            statuses: vec![JobStatus::Failed("boom".into())],
+            polls: std::sync::atomic::AtomicU32::new(0),
+        };
+
+        let job_id = JobId::new("transitioning-1");
+        let options = fast_wait_options();
+        let mut stream = backend.status_stream(&job_id, &options);
+        let first = stream.next().await.unwrap();
+        assert!(matches!(first, Ok(JobStatus::Failed(msg)) if msg == "boom"));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_status_stream_times_out_while_pending() {
+        let backend = TransitioningBackend {
+            capabilities: Capabilities::simulator(1),
+            statuses: vec![JobStatus::Running],
+            polls: std::sync::atomic::AtomicU32::new(0),
+        };
+        let options = WaitOptions::new(
+            crate::wait::PollSchedule::Fixed(std::time::Duration::from_millis(1)),
+            std::time::Duration::from_millis(5),
+        );
+
+        let job_id = JobId::new("transitioning-1");
+        let mut stream = backend.status_stream(&job_id, &options);
+        let first = stream.next().await.unwrap();
+        assert!(matches!(first, Ok(JobStatus::Running)));
+        let second = stream.next().await.unwrap();
+        assert!(matches!(second, Err(HalError::Timeout(_))));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_status_stream_emits_changed_status_immediately_without_waiting_out_the_poll_interval() {
+        let backend = TransitioningBackend {
+            capabilities: Capabilities::simulator(1),
+            statuses: vec![JobStatus::Queued, JobStatus::Running, JobStatus::Completed],
+            polls: std::sync::atomic::AtomicU32::new(0),
+        };
+        let options = WaitOptions::new(
+            crate::wait::PollSchedule::Fixed(std::time::Duration::from_millis(200)),
+            std::time::Duration::from_secs(5),
+        );
+
+        let job_id = JobId::new("transitioning-1");
+        let mut stream = backend.status_stream(&job_id, &options);
+
+        let start = std::time::Instant::now();
+        let first = stream.next().await.unwrap();
+        assert!(matches!(first, Ok(JobStatus::Queued)));
+        let second = stream.next().await.unwrap();
+        assert!(matches!(second, Ok(JobStatus::Running)));
+
+        assert!(
+            start.elapsed() < std::time::Duration::from_millis(200),
+            "a changed status must be emitted immediately, not after a full poll interval"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_submit_batch_preserves_order() {
+        let backend = StubBackend {
+            capabilities: Capabilities::simulator(1),
+        };
+
+        let results = backend.submit_batch(&[(), (), ()], 100).await;
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| matches!(r, Ok(id) if id.0 == "stub-1")));
+    }
+
+    struct EveryOtherSubmitFailsBackend {
+        capabilities: Capabilities,
+        attempts: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait]
+    impl Backend<()> for EveryOtherSubmitFailsBackend {
+        fn name(&self) -> &str {
+            "every-other-submit-fails"
+        }
+
+        fn capabilities(&self) -> &Capabilities {
+            &self.capabilities
+        }
+
+        async fn availability(&self) -> HalResult<BackendAvailability> {
+            Ok(BackendAvailability::always_available())
+        }
+
+        async fn validate(&self, _circuit: &()) -> HalResult<ValidationResult> {
+            Ok(ValidationResult::Valid)
+        }
+
+        async fn submit(&self, _circuit: &(), _shots: u32) -> HalResult<JobId> {
+            let attempt = self.attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if attempt.is_multiple_of(2) {
+                Ok(JobId::new(format!("batch-{attempt}")))
+            } else {
+                Err(HalError::InvalidCircuit("rejected".into()))
+            }
+        }
+
+        async fn status(&self, _job_id: &JobId) -> HalResult<JobStatus> {
+            Ok(JobStatus::Completed)
+        }
+
+        async fn result(&self, _job_id: &JobId) -> HalResult<ExecutionResult> {
+            Ok(ExecutionResult::default())
+        }
+
+        async fn cancel(&self, _job_id: &JobId) -> HalResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_batch_one_failure_does_not_block_others() {
+        let backend = EveryOtherSubmitFailsBackend {
+            capabilities: Capabilities::simulator(1),
+            attempts: std::sync::atomic::AtomicU32::new(0),
+        };
+
+        let results = backend.submit_batch(&[(), (), (), ()], 100).await;
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(HalError::InvalidCircuit(_))));
+        assert!(results[2].is_ok());
+        assert!(matches!(results[3], Err(HalError::InvalidCircuit(_))));
+    }
+
+    struct PerJobBackend {
+        capabilities: Capabilities,
+        statuses: std::collections::HashMap<String, JobStatus>,
+        cancelled: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Backend<()> for PerJobBackend {
+        fn name(&self) -> &str {
+            "per-job"
+        }
+
+        fn capabilities(&self) -> &Capabilities {
+            &self.capabilities
+        }
+
+        async fn availability(&self) -> HalResult<BackendAvailability> {
+            Ok(BackendAvailability::always_available())
+        }
+
+        async fn validate(&self, _circuit: &()) -> HalResult<ValidationResult> {
+            Ok(ValidationResult::Valid)
+        }
+
+        async fn submit(&self, _circuit: &(), _shots: u32) -> HalResult<JobId> {
+            unreachable!("wait_batch tests submit jobs out of band")
+        }
+
+        async fn status(&self, job_id: &JobId) -> HalResult<JobStatus> {
+            Ok(self.statuses.get(&job_id.0).cloned().unwrap_or(JobStatus::Completed))
+        }
+
+        async fn result(&self, _job_id: &JobId) -> HalResult<ExecutionResult> {
+            Ok(ExecutionResult::default())
+        }
+
+        async fn cancel(&self, job_id: &JobId) -> HalResult<()> {
+            self.cancelled.lock().unwrap().push(job_id.0.clone());
+            Ok(())
+        }
+    }
+
+    fn fast_wait_batch_options() -> WaitOptions {
+        WaitOptions::new(
+            crate::wait::PollSchedule::Fixed(std::time::Duration::from_millis(1)),
+            std::time::Duration::from_secs(5),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_wait_batch_collect_all_preserves_order() {
+        let backend = PerJobBackend {
+            capabilities: Capabilities::simulator(1),
+            statuses: std::collections::HashMap::from([
+                ("job-0".to_string(), JobStatus::Completed),
+                ("job-1".to_string(), JobStatus::Failed("boom".into())),
+                ("job-2".to_string(), JobStatus::Completed),
+            ]),
+            cancelled: std::sync::Mutex::new(Vec::new()),
+        };
+        let job_ids = [JobId::new("job-0"), JobId::new("job-1"), JobId::new("job-2")];
+
+        let results = backend
+            .wait_batch(&job_ids, &fast_wait_batch_options(), BatchWaitMode::CollectAll)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(matches!(&results[1], Err(HalError::JobFailed(msg)) if msg == "boom"));
+        assert!(results[2].is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wait_batch_fail_fast_returns_first_error() {
+        let backend = PerJobBackend {
+            capabilities: Capabilities::simulator(1),
+            statuses: std::collections::HashMap::from([
+                ("job-0".to_string(), JobStatus::Completed),
+                ("job-1".to_string(), JobStatus::Failed("boom".into())),
+                ("job-2".to_string(), JobStatus::Completed),
+            ]),
+            cancelled: std::sync::Mutex::new(Vec::new()),
+        };
+        let job_ids = [JobId::new("job-0"), JobId::new("job-1"), JobId::new("job-2")];
+
+        let result = backend.wait_batch(&job_ids, &fast_wait_batch_options(), BatchWaitMode::FailFast).await;
+
+        assert!(matches!(result, Err(HalError::JobFailed(msg)) if msg == "boom"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_batch_fail_fast_cancels_jobs_still_pending() {
+        let backend = PerJobBackend {
+            capabilities: Capabilities::simulator(1),
+            statuses: std::collections::HashMap::from([
+                ("job-0".to_string(), JobStatus::Running),
+                ("job-1".to_string(), JobStatus::Failed("boom".into())),
+                ("job-2".to_string(), JobStatus::Running),
+            ]),
+            cancelled: std::sync::Mutex::new(Vec::new()),
+        };
+        let job_ids = [JobId::new("job-0"), JobId::new("job-1"), JobId::new("job-2")];
+
+        let result = backend.wait_batch(&job_ids, &fast_wait_batch_options(), BatchWaitMode::FailFast).await;
+
+        assert!(matches!(result, Err(HalError::JobFailed(msg)) if msg == "boom"));
+        let mut cancelled = backend.cancelled.lock().unwrap().clone();
+        cancelled.sort();
+        assert_eq!(cancelled, vec!["job-0".to_string(), "job-2".to_string()]);
+    }
+
     #[test]
     fn test_validation_result_is_valid() {
         assert!(ValidationResult::Valid.is_valid());
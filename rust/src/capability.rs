@@ -16,6 +16,8 @@
 //! All edges in [`Topology`] are bidirectional: if `(a, b)` is present,
 //! both `a → b` and `b → a` are valid two-qubit interactions.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Hardware capabilities of a quantum backend.
@@ -44,6 +46,12 @@ pub struct Capabilities {
     /// Device-wide noise averages.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub noise_profile: Option<NoiseProfile>,
+    /// Parametric noise channels for noisy shot-based simulation.
+    ///
+    /// See the [`noise`](crate::noise) module. Absent for a noiseless
+    /// (ideal) device or simulator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub noise_channels: Option<Vec<crate::noise::NoiseChannelSpec>>,
 }
 
 impl Capabilities {
@@ -58,6 +66,7 @@ impl Capabilities {
             is_simulator: true,
             features: vec!["statevector".into(), "unitary".into()],
             noise_profile: None,
+            noise_channels: None,
         }
     }
 
@@ -72,34 +81,47 @@ impl Capabilities {
             is_simulator: false,
             features: vec![],
             noise_profile: None,
+            noise_channels: None,
         }
     }
 
     /// Create capabilities for IBM Eagle processors (127 qubits, ECR native).
+    ///
+    /// Defaults to the heavy-hex lattice of the smallest distance that
+    /// fits `num_qubits`; override with [`with_topology`](Self::with_topology)
+    /// for real device connectivity (the synthetic lattice may include
+    /// more qubits than requested, or omit calibration-excluded sites).
     pub fn ibm_eagle(name: impl Into<String>, num_qubits: u32) -> Self {
         Self {
             name: name.into(),
             num_qubits,
             gate_set: GateSet::ibm_eagle(),
-            topology: Topology::custom(vec![]), // Use with_topology() for real connectivity
+            topology: Topology::heavy_hex(Topology::heavy_hex_distance_for(num_qubits)),
             max_shots: 100_000,
             is_simulator: false,
             features: vec!["dynamic_circuits".into()],
             noise_profile: None,
+            noise_channels: None,
         }
     }
 
     /// Create capabilities for IBM Heron processors (156 qubits, CZ native).
+    ///
+    /// Defaults to the heavy-hex lattice of the smallest distance that
+    /// fits `num_qubits`; override with [`with_topology`](Self::with_topology)
+    /// for real device connectivity (the synthetic lattice may include
+    /// more qubits than requested, or omit calibration-excluded sites).
     pub fn ibm_heron(name: impl Into<String>, num_qubits: u32) -> Self {
         Self {
             name: name.into(),
             num_qubits,
             gate_set: GateSet::ibm_heron(),
-            topology: Topology::custom(vec![]), // Use with_topology() for real connectivity
+            topology: Topology::heavy_hex(Topology::heavy_hex_distance_for(num_qubits)),
             max_shots: 100_000,
             is_simulator: false,
             features: vec!["dynamic_circuits".into()],
             noise_profile: None,
+            noise_channels: None,
         }
     }
 
@@ -114,6 +136,7 @@ impl Capabilities {
             is_simulator: false,
             features: vec!["shuttling".into(), "zoned".into()],
             noise_profile: None,
+            noise_channels: None,
         }
     }
 
@@ -131,6 +154,7 @@ impl Capabilities {
             is_simulator: false,
             features: vec![],
             noise_profile: None,
+            noise_channels: None,
         }
     }
 
@@ -145,6 +169,7 @@ impl Capabilities {
             is_simulator: false,
             features: vec![],
             noise_profile: None,
+            noise_channels: None,
         }
     }
 
@@ -168,8 +193,11 @@ impl Capabilities {
 ///
 /// The `native` list identifies gates that execute without decomposition.
 /// If `native` is empty, all supported gates are considered native
-/// (typical for simulators).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// (typical for simulators). Non-native gates may have a [`decompose`]
+/// template describing how to realize them from the native set.
+///
+/// [`decompose`]: GateSet::decompose
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GateSet {
     /// Single-qubit gates supported.
     pub single_qubit: Vec<String>,
@@ -180,6 +208,15 @@ pub struct GateSet {
     pub three_qubit: Vec<String>,
     /// Native gates (execute without decomposition on this backend).
     pub native: Vec<String>,
+    /// Decomposition templates for non-native gates, keyed by gate name.
+    ///
+    /// Each template is an ordered list of [`DecompStep`]s using relative
+    /// operand indices. Steps may themselves be non-native — use
+    /// [`decompose`](GateSet::decompose) recursively or
+    /// [`can_realize`](GateSet::can_realize)/[`count_two_qubit`](GateSet::count_two_qubit),
+    /// which already do so.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub decompositions: BTreeMap<String, Vec<DecompStep>>,
 }
 
 impl GateSet {
@@ -190,6 +227,17 @@ impl GateSet {
             two_qubit: vec!["cz".into()],
             three_qubit: vec![],
             native: vec!["prx".into(), "cz".into()],
+            decompositions: [
+                ("h", vec![step("prx", [0]), step("prx", [0])]),
+                (
+                    "cx",
+                    vec![step("prx", [1]), step("cz", [0, 1]), step("prx", [1])],
+                ),
+                ("swap", swap_via_cx()),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect(),
         }
     }
 
@@ -202,6 +250,23 @@ impl GateSet {
             two_qubit: vec!["ecr".into()],
             three_qubit: vec![],
             native: vec!["rz".into(), "sx".into(), "x".into(), "ecr".into()],
+            decompositions: [
+                ("h", vec![step("rz", [0]), step("sx", [0]), step("rz", [0])]),
+                (
+                    "cx",
+                    vec![
+                        step("rz", [0]),
+                        step("sx", [0]),
+                        step("ecr", [0, 1]),
+                        step("x", [1]),
+                        step("rz", [0]),
+                    ],
+                ),
+                ("swap", swap_via_cx()),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect(),
         }
     }
 
@@ -230,6 +295,16 @@ impl GateSet {
                 "h".into(),
                 "rzz".into(),
             ],
+            decompositions: [
+                (
+                    "cx",
+                    vec![step("h", [1]), step("cz", [0, 1]), step("h", [1])],
+                ),
+                ("swap", swap_via_cx()),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect(),
         }
     }
 
@@ -272,6 +347,7 @@ impl GateSet {
             ],
             three_qubit: vec!["ccx".into(), "cswap".into()],
             native: vec![],
+            decompositions: BTreeMap::new(),
         }
     }
 
@@ -282,6 +358,17 @@ impl GateSet {
             two_qubit: vec!["cz".into()],
             three_qubit: vec![],
             native: vec!["rx".into(), "rz".into(), "cz".into()],
+            decompositions: [
+                ("h", vec![step("rz", [0]), step("rx", [0]), step("rz", [0])]),
+                (
+                    "cx",
+                    vec![step("h", [1]), step("cz", [0, 1]), step("h", [1])],
+                ),
+                ("swap", swap_via_cx()),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect(),
         }
     }
 
@@ -292,6 +379,23 @@ impl GateSet {
             two_qubit: vec!["xx".into()],
             three_qubit: vec![],
             native: vec!["rx".into(), "ry".into(), "rz".into(), "xx".into()],
+            decompositions: [
+                ("h", vec![step("rz", [0]), step("ry", [0]), step("rz", [0])]),
+                (
+                    "cx",
+                    vec![
+                        step("ry", [0]),
+                        step("xx", [0, 1]),
+                        step("rx", [0]),
+                        step("rx", [1]),
+                        step("ry", [0]),
+                    ],
+                ),
+                ("swap", swap_via_cx()),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect(),
         }
     }
 
@@ -302,6 +406,7 @@ impl GateSet {
             two_qubit: vec!["cz".into()],
             three_qubit: vec![],
             native: vec!["rz".into(), "rx".into(), "ry".into(), "cz".into()],
+            decompositions: BTreeMap::new(),
         }
     }
 
@@ -323,6 +428,69 @@ impl GateSet {
             self.native.iter().any(|g| g == gate)
         }
     }
+
+    /// Look up the decomposition template for a gate, if one is registered.
+    pub fn decompose(&self, gate: &str) -> Option<&[DecompStep]> {
+        self.decompositions.get(gate).map(Vec::as_slice)
+    }
+
+    /// Count the number of two-qubit operations needed to realize `gate`,
+    /// recursively expanding any non-native steps in its decomposition.
+    ///
+    /// Returns `0` if `gate` is neither native nor decomposable (the
+    /// gate cannot be realized at all — see [`can_realize`](Self::can_realize)).
+    pub fn count_two_qubit(&self, gate: &str) -> usize {
+        if let Some(steps) = self.decompose(gate) {
+            steps.iter().map(|s| self.count_two_qubit(&s.gate)).sum()
+        } else if self.is_native(gate) && self.two_qubit.iter().any(|g| g == gate) {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Check whether `gate` can be realized on this backend: it is either
+    /// native, or has a decomposition whose leaves are all (recursively)
+    /// native.
+    pub fn can_realize(&self, gate: &str) -> bool {
+        if self.is_native(gate) {
+            return true;
+        }
+        match self.decompose(gate) {
+            Some(steps) => steps.iter().all(|s| self.can_realize(&s.gate)),
+            None => false,
+        }
+    }
+}
+
+/// One step of a gate decomposition template.
+///
+/// `qubit_args` uses operand indices relative to the gate being
+/// decomposed (e.g. for a two-qubit gate, `0` is the control/first
+/// operand and `1` is the target/second operand) rather than absolute
+/// qubit indices — the template is instantiated against whatever qubits
+/// the original gate acted on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecompStep {
+    /// Name of the gate applied by this step.
+    pub gate: String,
+    /// Relative operand indices this step acts on.
+    pub qubit_args: Vec<usize>,
+}
+
+fn step(gate: &str, qubit_args: impl IntoIterator<Item = usize>) -> DecompStep {
+    DecompStep {
+        gate: gate.to_string(),
+        qubit_args: qubit_args.into_iter().collect(),
+    }
+}
+
+/// `swap(0, 1) = cx(0,1) . cx(1,0) . cx(0,1)`, the standard decomposition
+/// used by every gate set in this module that registers a `swap`
+/// decomposition, regardless of which gate `cx` itself further
+/// decomposes to.
+fn swap_via_cx() -> Vec<DecompStep> {
+    vec![step("cx", [0, 1]), step("cx", [1, 0]), step("cx", [0, 1])]
 }
 
 /// Qubit connectivity topology.
@@ -335,25 +503,34 @@ pub struct Topology {
     pub kind: TopologyKind,
     /// Coupling edges (pairs of connected qubits). Bidirectional.
     pub edges: Vec<(u32, u32)>,
+    /// Adjacency list built from `edges`, memoized on first use by
+    /// [`neighbors`](Topology::neighbors)/[`degree`](Topology::degree) so
+    /// repeated queries over a 127/156-qubit topology don't each rescan
+    /// `edges` from scratch. Not part of the wire format — skipped by
+    /// serde and rebuilt lazily after deserialization.
+    #[serde(skip)]
+    adjacency_cache: std::sync::OnceLock<BTreeMap<u32, Vec<u32>>>,
 }
 
 impl Topology {
-    /// Create a linear topology.
-    pub fn linear(n: u32) -> Self {
-        let edges: Vec<_> = (0..n.saturating_sub(1)).map(|i| (i, i + 1)).collect();
+    fn new(kind: TopologyKind, edges: Vec<(u32, u32)>) -> Self {
         Self {
-            kind: TopologyKind::Linear,
+            kind,
             edges,
+            adjacency_cache: std::sync::OnceLock::new(),
         }
     }
 
+    /// Create a linear topology.
+    pub fn linear(n: u32) -> Self {
+        let edges: Vec<_> = (0..n.saturating_sub(1)).map(|i| (i, i + 1)).collect();
+        Self::new(TopologyKind::Linear, edges)
+    }
+
     /// Create a star topology.
     pub fn star(n: u32) -> Self {
         let edges: Vec<_> = (1..n).map(|i| (0, i)).collect();
-        Self {
-            kind: TopologyKind::Star,
-            edges,
-        }
+        Self::new(TopologyKind::Star, edges)
     }
 
     /// Create a fully connected topology.
@@ -364,10 +541,7 @@ impl Topology {
                 edges.push((i, j));
             }
         }
-        Self {
-            kind: TopologyKind::FullyConnected,
-            edges,
-        }
+        Self::new(TopologyKind::FullyConnected, edges)
     }
 
     /// Create a grid topology.
@@ -384,18 +558,12 @@ impl Topology {
                 }
             }
         }
-        Self {
-            kind: TopologyKind::Grid { rows, cols },
-            edges,
-        }
+        Self::new(TopologyKind::Grid { rows, cols }, edges)
     }
 
     /// Create a custom topology from edges.
     pub fn custom(edges: Vec<(u32, u32)>) -> Self {
-        Self {
-            kind: TopologyKind::Custom,
-            edges,
-        }
+        Self::new(TopologyKind::Custom, edges)
     }
 
     /// Create a neutral-atom topology with zones.
@@ -420,10 +588,93 @@ impl Topology {
             }
         }
 
-        Self {
-            kind: TopologyKind::NeutralAtom { zones },
-            edges,
+        Self::new(TopologyKind::NeutralAtom { zones }, edges)
+    }
+
+    /// Create an IBM heavy-hexagon topology of the given code distance.
+    ///
+    /// Lays out `2*distance - 1` rows: even-indexed rows are full chains
+    /// of `2*distance - 1` qubits connected linearly left-to-right
+    /// ("code" rows); odd-indexed rows are sparse bridge rows containing
+    /// only the vertical-link qubits ("flag" rows), connected to the
+    /// chain row above and below at every other column. The starting
+    /// column of each bridge row alternates so the pattern tiles into
+    /// hexagons of degree ≤ 3. Qubits are numbered in row-major order as
+    /// they are emitted.
+    pub fn heavy_hex(distance: u32) -> Self {
+        let distance = distance.max(1);
+        let width = (2 * distance - 1) as usize;
+        let num_rows = 2 * distance as usize - 1;
+
+        let mut next_id = 0u32;
+        let mut chain_rows: BTreeMap<usize, Vec<u32>> = BTreeMap::new();
+        let mut bridge_rows: BTreeMap<usize, BTreeMap<usize, u32>> = BTreeMap::new();
+        let mut bridge_count = 0usize;
+
+        for row in 0..num_rows {
+            if row % 2 == 0 {
+                let ids: Vec<u32> = (0..width as u32).map(|i| next_id + i).collect();
+                next_id += width as u32;
+                chain_rows.insert(row, ids);
+            } else {
+                let start_col = bridge_count % 2;
+                bridge_count += 1;
+                let mut cols = BTreeMap::new();
+                let mut col = start_col;
+                while col < width {
+                    cols.insert(col, next_id);
+                    next_id += 1;
+                    col += 2;
+                }
+                bridge_rows.insert(row, cols);
+            }
+        }
+
+        let mut edges = vec![];
+        for ids in chain_rows.values() {
+            for pair in ids.windows(2) {
+                edges.push(canonical_edge(pair[0], pair[1]));
+            }
         }
+        for (&row, cols) in &bridge_rows {
+            for (&col, &id) in cols {
+                if let Some(above) = row.checked_sub(1).and_then(|r| chain_rows.get(&r)) {
+                    edges.push(canonical_edge(above[col], id));
+                }
+                if let Some(below) = chain_rows.get(&(row + 1)) {
+                    edges.push(canonical_edge(id, below[col]));
+                }
+            }
+        }
+
+        Self::new(TopologyKind::HeavyHex, edges)
+    }
+
+    /// Number of qubits a [`Topology::heavy_hex`] lattice of this
+    /// `distance` would contain, without building the full edge list.
+    fn heavy_hex_qubit_count(distance: u32) -> u32 {
+        let distance = distance.max(1);
+        let width = 2 * distance - 1;
+        let mut total = 0u32;
+        for row in 0..(2 * distance - 1) {
+            if row % 2 == 0 {
+                total += width;
+            } else {
+                let start_col = (row / 2) % 2;
+                total += (start_col..width).step_by(2).count() as u32;
+            }
+        }
+        total
+    }
+
+    /// Smallest `distance` whose [`Topology::heavy_hex`] qubit count is
+    /// `>= num_qubits`.
+    pub fn heavy_hex_distance_for(num_qubits: u32) -> u32 {
+        let mut distance = 1;
+        while Self::heavy_hex_qubit_count(distance) < num_qubits {
+            distance += 1;
+        }
+        distance
     }
 
     /// Check if two qubits are connected.
@@ -432,6 +683,173 @@ impl Topology {
             .iter()
             .any(|&(a, b)| (a == q1 && b == q2) || (a == q2 && b == q1))
     }
+
+    /// Adjacency list built from `edges`, memoized in `adjacency_cache` so
+    /// repeated calls don't rescan `edges` from scratch. Looking neighbors
+    /// up against this instead is what keeps `distance_matrix()`,
+    /// `connected_components()`, and `neighbors()`/`degree()` tractable on
+    /// 127/156-qubit topologies under repeated queries.
+    fn adjacency(&self) -> &BTreeMap<u32, Vec<u32>> {
+        self.adjacency_cache.get_or_init(|| {
+            let mut adj: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+            for &(a, b) in &self.edges {
+                adj.entry(a).or_default().push(b);
+                adj.entry(b).or_default().push(a);
+            }
+            adj
+        })
+    }
+
+    /// Qubits directly coupled to `q`.
+    pub fn neighbors(&self, q: u32) -> Vec<u32> {
+        self.adjacency().get(&q).cloned().unwrap_or_default()
+    }
+
+    /// Number of qubits directly coupled to `q`.
+    pub fn degree(&self, q: u32) -> usize {
+        self.neighbors(q).len()
+    }
+
+    /// One past the highest qubit index referenced by any edge, i.e. the
+    /// size of the vertex set the graph-query methods operate over.
+    ///
+    /// Qubits above that index (e.g. the only qubit of a single-qubit
+    /// `full()` topology) are not part of this vertex set; qubits below it
+    /// that appear in no edge are.
+    fn vertex_count(&self) -> usize {
+        self.edges
+            .iter()
+            .flat_map(|&(a, b)| [a, b])
+            .max()
+            .map_or(0, |m| m as usize + 1)
+    }
+
+    /// Shortest path between `a` and `b` by number of hops, via BFS over
+    /// the bidirectional edge set. `Some(vec![a])` when `a == b`; `None`
+    /// when `b` is unreachable from `a`.
+    pub fn shortest_path(&self, a: u32, b: u32) -> Option<Vec<u32>> {
+        if a == b {
+            return Some(vec![a]);
+        }
+        let adj = self.adjacency();
+        let mut visited = std::collections::BTreeSet::from([a]);
+        let mut queue = std::collections::VecDeque::from([vec![a]]);
+
+        while let Some(path) = queue.pop_front() {
+            let &last = path.last().expect("path is never empty");
+            for &next in adj.get(&last).into_iter().flatten() {
+                if next == b {
+                    let mut full_path = path;
+                    full_path.push(next);
+                    return Some(full_path);
+                }
+                if visited.insert(next) {
+                    let mut extended = path.clone();
+                    extended.push(next);
+                    queue.push_back(extended);
+                }
+            }
+        }
+        None
+    }
+
+    /// All-pairs shortest-path hop counts.
+    ///
+    /// `distance_matrix()[i][j]` is `Some(hops)` between qubits `i` and
+    /// `j`, or `None` if unreachable; the diagonal is always `Some(0)`.
+    /// Indexed by literal qubit id, sized to one past the highest qubit
+    /// id referenced by any edge.
+    pub fn distance_matrix(&self) -> Vec<Vec<Option<u32>>> {
+        let n = self.vertex_count();
+        let adj = self.adjacency();
+        let mut matrix = vec![vec![None; n]; n];
+
+        for start in 0..n as u32 {
+            matrix[start as usize][start as usize] = Some(0);
+            let mut queue = std::collections::VecDeque::from([start]);
+            while let Some(cur) = queue.pop_front() {
+                let dist = matrix[start as usize][cur as usize].expect("visited has a distance");
+                for &next in adj.get(&cur).into_iter().flatten() {
+                    if matrix[start as usize][next as usize].is_none() {
+                        matrix[start as usize][next as usize] = Some(dist + 1);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        matrix
+    }
+
+    /// Connected components of the coupling graph, each sorted ascending.
+    ///
+    /// Covers qubits `0..vertex_count()`; a qubit with no edges forms its
+    /// own singleton component.
+    pub fn connected_components(&self) -> Vec<Vec<u32>> {
+        let n = self.vertex_count();
+        let adj = self.adjacency();
+        let mut visited = vec![false; n];
+        let mut components = vec![];
+
+        for start in 0..n as u32 {
+            if visited[start as usize] {
+                continue;
+            }
+            let mut component = vec![];
+            let mut stack = vec![start];
+            visited[start as usize] = true;
+            while let Some(cur) = stack.pop() {
+                component.push(cur);
+                for &next in adj.get(&cur).into_iter().flatten() {
+                    if !visited[next as usize] {
+                        visited[next as usize] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    /// Shortest path between `a` and `b`, with each hop classified as
+    /// [`PathStep::Coupled`] (a direct coupling edge) or
+    /// [`PathStep::Shuttle`] (requires physically moving an atom between
+    /// [`TopologyKind::NeutralAtom`] zones — no such edge exists).
+    ///
+    /// For every other `kind`, every hop is `Coupled`: there is no
+    /// shuttling concept outside neutral-atom devices.
+    pub fn routed_path(&self, a: u32, b: u32) -> Option<Vec<PathStep>> {
+        let TopologyKind::NeutralAtom { zones } = self.kind else {
+            let path = self.shortest_path(a, b)?;
+            return Some(path.windows(2).map(|w| PathStep::Coupled(w[1])).collect());
+        };
+        if a == b {
+            return Some(vec![]);
+        }
+
+        let num_qubits = self.vertex_count() as u32;
+        let qubits_per_zone = (num_qubits / zones.max(1)).max(1);
+        let zone_of = |q: u32| (q / qubits_per_zone).min(zones.saturating_sub(1));
+
+        if zone_of(a) == zone_of(b) {
+            let path = self.shortest_path(a, b)?;
+            Some(path.windows(2).map(|w| PathStep::Coupled(w[1])).collect())
+        } else {
+            // No coupling edge crosses zones — shuttle directly to the target.
+            Some(vec![PathStep::Shuttle(b)])
+        }
+    }
+}
+
+/// One hop of a [`Topology::routed_path`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PathStep {
+    /// Hop over a direct coupling edge, to this qubit.
+    Coupled(u32),
+    /// Hop that requires physically shuttling an atom between zones, to
+    /// this qubit.
+    Shuttle(u32),
 }
 
 /// Kind of qubit topology.
@@ -460,11 +878,13 @@ pub enum TopologyKind {
 /// Device-wide noise averages reported by a backend.
 ///
 /// These are aggregate characterization numbers — suitable for routing
-/// and coarse-grained compilation decisions.
+/// and coarse-grained compilation decisions. For per-qubit/per-edge
+/// detail (e.g. to pick a high-fidelity sub-topology), see
+/// [`NoiseProfile::qubit_errors`] and [`NoiseProfile::edge_errors`].
 ///
 /// All fidelity values are in `[0.0, 1.0]` where `1.0` means perfect.
 /// Time values (T1, T2, gate_time) are in **microseconds**.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct NoiseProfile {
     /// T1 relaxation time (device average, microseconds).
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -484,6 +904,164 @@ pub struct NoiseProfile {
     /// Average gate execution time (microseconds).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gate_time: Option<f64>,
+    /// Per-qubit characterization, keyed by qubit index.
+    ///
+    /// Absent when only device-wide averages are known.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub qubit_errors: BTreeMap<u32, QubitNoise>,
+    /// Per-edge characterization, keyed by the canonicalized
+    /// (low-qubit-first) coupling edge as `"{a}-{b}"`.
+    ///
+    /// String-keyed rather than tuple-keyed so the map round-trips
+    /// through `serde_json` — JSON object keys must be strings, and
+    /// `serde_json` rejects a populated tuple-keyed map. Use
+    /// [`edge`](NoiseProfile::edge)/[`with_edge`](NoiseProfile::with_edge)
+    /// rather than indexing this map directly.
+    ///
+    /// Absent when only device-wide averages are known.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub edge_errors: BTreeMap<String, EdgeNoise>,
+}
+
+impl NoiseProfile {
+    /// Look up per-qubit characterization for `q`, if known.
+    pub fn qubit(&self, q: u32) -> Option<&QubitNoise> {
+        self.qubit_errors.get(&q)
+    }
+
+    /// Look up per-edge characterization for the (order-insensitive)
+    /// coupling edge `(a, b)`, if known.
+    ///
+    /// Matches [`Topology`]'s bidirectional convention: `edge(a, b)`
+    /// and `edge(b, a)` return the same entry.
+    pub fn edge(&self, a: u32, b: u32) -> Option<&EdgeNoise> {
+        self.edge_errors.get(&edge_key(a, b))
+    }
+
+    /// Record per-qubit characterization for `q`.
+    pub fn with_qubit(mut self, q: u32, noise: QubitNoise) -> Self {
+        self.qubit_errors.insert(q, noise);
+        self
+    }
+
+    /// Record per-edge characterization for the (order-insensitive)
+    /// coupling edge `(a, b)`.
+    pub fn with_edge(mut self, a: u32, b: u32, noise: EdgeNoise) -> Self {
+        self.edge_errors.insert(edge_key(a, b), noise);
+        self
+    }
+
+    /// The highest-fidelity edge, if any fine-grained edge data is present.
+    pub fn best_edge(&self) -> Option<((u32, u32), &EdgeNoise)> {
+        self.edge_errors
+            .iter()
+            .max_by(|(_, a), (_, b)| {
+                a.two_qubit_fidelity
+                    .partial_cmp(&b.two_qubit_fidelity)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(key, noise)| (parse_edge_key(key), noise))
+    }
+
+    /// The `n` qubits with the lowest single-qubit fidelity, worst first.
+    ///
+    /// Only considers qubits present in `qubit_errors`; qubits with no
+    /// recorded fidelity are excluded rather than assumed worst.
+    pub fn worst_qubits(&self, n: usize) -> Vec<u32> {
+        let mut rated: Vec<(u32, f64)> = self
+            .qubit_errors
+            .iter()
+            .filter_map(|(&q, noise)| noise.single_qubit_fidelity.map(|f| (q, f)))
+            .collect();
+        rated.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        rated.into_iter().take(n).map(|(q, _)| q).collect()
+    }
+
+    /// T1 for `q`, falling back to the device-wide average when no
+    /// per-qubit data is recorded.
+    pub fn t1_for(&self, q: u32) -> Option<f64> {
+        self.qubit(q).and_then(|n| n.t1).or(self.t1)
+    }
+
+    /// T2 for `q`, falling back to the device-wide average when no
+    /// per-qubit data is recorded.
+    pub fn t2_for(&self, q: u32) -> Option<f64> {
+        self.qubit(q).and_then(|n| n.t2).or(self.t2)
+    }
+
+    /// Two-qubit fidelity for edge `(a, b)`, falling back to the
+    /// device-wide average when no per-edge data is recorded.
+    pub fn two_qubit_fidelity_for(&self, a: u32, b: u32) -> Option<f64> {
+        self.edge(a, b)
+            .map(|n| n.two_qubit_fidelity)
+            .or(self.two_qubit_fidelity)
+    }
+}
+
+/// Canonicalize a coupling edge to low-qubit-first order, matching
+/// [`Topology`]'s bidirectional convention.
+fn canonical_edge(a: u32, b: u32) -> (u32, u32) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// String form of [`canonical_edge`], for use as a `serde_json`-safe map key.
+fn edge_key(a: u32, b: u32) -> String {
+    let (lo, hi) = canonical_edge(a, b);
+    format!("{lo}-{hi}")
+}
+
+/// Inverse of [`edge_key`] for a key this module produced.
+fn parse_edge_key(key: &str) -> (u32, u32) {
+    let (lo, hi) = key.split_once('-').expect("edge_errors keys are always \"lo-hi\"");
+    (lo.parse().expect("edge key component is always a u32"), hi.parse().expect("edge key component is always a u32"))
+}
+
+/// Per-qubit noise characterization.
+///
+/// All fidelity values are in `[0.0, 1.0]`. Time values (T1, T2) are in
+/// **microseconds**.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QubitNoise {
+    /// T1 relaxation time (microseconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub t1: Option<f64>,
+    /// T2 dephasing time (microseconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub t2: Option<f64>,
+    /// Single-qubit gate fidelity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub single_qubit_fidelity: Option<f64>,
+    /// Readout confusion matrix `[[p00, p01], [p10, p11]]`, where
+    /// `p_ij` is the probability of reading out `j` when the qubit was
+    /// prepared in state `i`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub readout_confusion: Option<[[f64; 2]; 2]>,
+}
+
+impl QubitNoise {
+    /// Readout fidelity derived from the confusion matrix diagonal
+    /// (average of `p00` and `p11`), if known.
+    pub fn readout_fidelity(&self) -> Option<f64> {
+        self.readout_confusion
+            .map(|m| (m[0][0] + m[1][1]) / 2.0)
+    }
+}
+
+/// Per-edge (two-qubit coupling) noise characterization.
+///
+/// `two_qubit_fidelity` is in `[0.0, 1.0]`; `gate_time` is in
+/// **microseconds**.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgeNoise {
+    /// Two-qubit gate fidelity on this edge.
+    pub two_qubit_fidelity: f64,
+    /// Two-qubit gate execution time on this edge (microseconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gate_time: Option<f64>,
 }
 
 #[cfg(test)]
@@ -547,6 +1125,101 @@ mod tests {
         assert!(!topo.is_connected(0, 5));
     }
 
+    #[test]
+    fn test_neighbors_and_degree() {
+        let topo = Topology::linear(4);
+        assert_eq!(topo.neighbors(0), vec![1]);
+        let mut mid_neighbors = topo.neighbors(1);
+        mid_neighbors.sort_unstable();
+        assert_eq!(mid_neighbors, vec![0, 2]);
+        assert_eq!(topo.degree(1), 2);
+        assert_eq!(topo.degree(3), 1);
+    }
+
+    #[test]
+    fn test_shortest_path_linear() {
+        let topo = Topology::linear(5);
+        assert_eq!(topo.shortest_path(0, 4), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(topo.shortest_path(2, 2), Some(vec![2]));
+    }
+
+    #[test]
+    fn test_shortest_path_unreachable() {
+        let topo = Topology::custom(vec![(0, 1), (2, 3)]);
+        assert_eq!(topo.shortest_path(0, 3), None);
+    }
+
+    #[test]
+    fn test_distance_matrix_linear() {
+        let topo = Topology::linear(3);
+        let matrix = topo.distance_matrix();
+        assert_eq!(matrix[0][0], Some(0));
+        assert_eq!(matrix[0][1], Some(1));
+        assert_eq!(matrix[0][2], Some(2));
+        assert_eq!(matrix[2][0], Some(2));
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let topo = Topology::custom(vec![(0, 1), (2, 3)]);
+        let mut components = topo.connected_components();
+        components.sort();
+        assert_eq!(components, vec![vec![0, 1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_routed_path_non_neutral_atom_all_coupled() {
+        let topo = Topology::linear(3);
+        let steps = topo.routed_path(0, 2).unwrap();
+        assert_eq!(steps, vec![PathStep::Coupled(1), PathStep::Coupled(2)]);
+    }
+
+    #[test]
+    fn test_routed_path_neutral_atom_same_zone_is_coupled() {
+        let topo = Topology::neutral_atom(6, 2);
+        let steps = topo.routed_path(0, 1).unwrap();
+        assert!(matches!(steps[..], [PathStep::Coupled(1)]));
+    }
+
+    #[test]
+    fn test_routed_path_neutral_atom_cross_zone_is_shuttle() {
+        let topo = Topology::neutral_atom(6, 2);
+        let steps = topo.routed_path(0, 4).unwrap();
+        assert_eq!(steps, vec![PathStep::Shuttle(4)]);
+    }
+
+    #[test]
+    fn test_heavy_hex_max_degree_and_qubit_count_distance_3() {
+        let topo = Topology::heavy_hex(3);
+        assert_eq!(topo.kind, TopologyKind::HeavyHex);
+
+        let mut degree: std::collections::BTreeMap<u32, u32> = std::collections::BTreeMap::new();
+        for &(a, b) in &topo.edges {
+            *degree.entry(a).or_default() += 1;
+            *degree.entry(b).or_default() += 1;
+        }
+        assert!(degree.values().all(|&d| d <= 3));
+
+        let num_qubits = degree.keys().max().copied().unwrap() + 1;
+        assert_eq!(num_qubits, Topology::heavy_hex_qubit_count(3));
+    }
+
+    #[test]
+    fn test_heavy_hex_distance_for() {
+        let d = Topology::heavy_hex_distance_for(20);
+        assert!(Topology::heavy_hex_qubit_count(d) >= 20);
+        if d > 1 {
+            assert!(Topology::heavy_hex_qubit_count(d - 1) < 20);
+        }
+    }
+
+    #[test]
+    fn test_ibm_eagle_heavy_hex_topology() {
+        let caps = Capabilities::ibm_eagle("ibm_test", 127);
+        assert_eq!(caps.topology.kind, TopologyKind::HeavyHex);
+        assert!(!caps.topology.edges.is_empty());
+    }
+
     #[test]
     fn test_gate_set_is_native() {
         let gs = GateSet {
@@ -554,6 +1227,7 @@ mod tests {
             two_qubit: vec!["cx".into()],
             three_qubit: vec![],
             native: vec!["rx".into(), "cx".into()],
+            decompositions: BTreeMap::new(),
         };
         assert!(gs.is_native("rx"));
         assert!(gs.is_native("cx"));
@@ -567,9 +1241,175 @@ mod tests {
             two_qubit: vec!["cx".into()],
             three_qubit: vec![],
             native: vec![],
+            decompositions: BTreeMap::new(),
         };
         assert!(gs.is_native("h"));
         assert!(gs.is_native("cx"));
         assert!(!gs.is_native("cz"));
     }
+
+    #[test]
+    fn test_noise_profile_edge_order_insensitive() {
+        let profile = NoiseProfile::default().with_edge(
+            2,
+            0,
+            EdgeNoise {
+                two_qubit_fidelity: 0.99,
+                gate_time: Some(0.3),
+            },
+        );
+        assert!(profile.edge(0, 2).is_some());
+        assert!(profile.edge(2, 0).is_some());
+        assert_eq!(profile.edge(0, 2).unwrap().two_qubit_fidelity, 0.99);
+        assert!(profile.edge(1, 2).is_none());
+    }
+
+    #[test]
+    fn test_noise_profile_json_round_trips_with_populated_edge_errors() {
+        let profile = NoiseProfile::default()
+            .with_qubit(
+                0,
+                QubitNoise {
+                    t1: Some(100.0),
+                    t2: Some(80.0),
+                    single_qubit_fidelity: Some(0.999),
+                    readout_confusion: Some([[0.98, 0.02], [0.03, 0.97]]),
+                },
+            )
+            .with_edge(
+                2,
+                0,
+                EdgeNoise {
+                    two_qubit_fidelity: 0.97,
+                    gate_time: Some(0.3),
+                },
+            );
+
+        let json = serde_json::to_string(&profile).expect("populated NoiseProfile must serialize");
+        let parsed: NoiseProfile = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.edge(0, 2).unwrap().two_qubit_fidelity, 0.97);
+        assert_eq!(parsed.qubit(0).unwrap().t1, Some(100.0));
+    }
+
+    #[test]
+    fn test_noise_profile_best_edge() {
+        let profile = NoiseProfile::default()
+            .with_edge(
+                0,
+                1,
+                EdgeNoise {
+                    two_qubit_fidelity: 0.95,
+                    gate_time: None,
+                },
+            )
+            .with_edge(
+                1,
+                2,
+                EdgeNoise {
+                    two_qubit_fidelity: 0.99,
+                    gate_time: None,
+                },
+            );
+        let (edge, noise) = profile.best_edge().unwrap();
+        assert_eq!(edge, (1, 2));
+        assert_eq!(noise.two_qubit_fidelity, 0.99);
+    }
+
+    #[test]
+    fn test_noise_profile_worst_qubits() {
+        let profile = NoiseProfile::default()
+            .with_qubit(
+                0,
+                QubitNoise {
+                    single_qubit_fidelity: Some(0.999),
+                    ..Default::default()
+                },
+            )
+            .with_qubit(
+                1,
+                QubitNoise {
+                    single_qubit_fidelity: Some(0.95),
+                    ..Default::default()
+                },
+            )
+            .with_qubit(
+                2,
+                QubitNoise {
+                    single_qubit_fidelity: Some(0.98),
+                    ..Default::default()
+                },
+            );
+        assert_eq!(profile.worst_qubits(2), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_noise_profile_fallback_to_device_wide() {
+        let profile = NoiseProfile {
+            t1: Some(80.0),
+            two_qubit_fidelity: Some(0.97),
+            ..Default::default()
+        };
+        assert_eq!(profile.t1_for(0), Some(80.0));
+        assert_eq!(profile.two_qubit_fidelity_for(0, 1), Some(0.97));
+
+        let profile = profile.with_qubit(
+            0,
+            QubitNoise {
+                t1: Some(120.0),
+                ..Default::default()
+            },
+        );
+        assert_eq!(profile.t1_for(0), Some(120.0));
+        assert_eq!(profile.t1_for(1), Some(80.0));
+    }
+
+    #[test]
+    fn test_qubit_noise_readout_fidelity() {
+        let noise = QubitNoise {
+            readout_confusion: Some([[0.98, 0.02], [0.03, 0.97]]),
+            ..Default::default()
+        };
+        assert!((noise.readout_fidelity().unwrap() - 0.975).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_decompose_cx_h_swap_resolve_on_every_real_backend() {
+        for gate_set in [
+            GateSet::iqm(),
+            GateSet::ibm_eagle(),
+            GateSet::ibm_heron(),
+            GateSet::rigetti(),
+            GateSet::ionq(),
+        ] {
+            for gate in ["cx", "h", "swap"] {
+                assert!(
+                    gate_set.can_realize(gate),
+                    "{gate} should be realizable with native: {:?}",
+                    gate_set.native
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_two_qubit_recursive() {
+        let gs = GateSet::ibm_eagle();
+        assert_eq!(gs.count_two_qubit("ecr"), 1);
+        assert_eq!(gs.count_two_qubit("cx"), 1);
+        assert_eq!(gs.count_two_qubit("swap"), 3);
+    }
+
+    #[test]
+    fn test_can_realize_unknown_gate() {
+        let gs = GateSet::iqm();
+        assert!(!gs.can_realize("toffoli"));
+        assert_eq!(gs.count_two_qubit("toffoli"), 0);
+    }
+
+    #[test]
+    fn test_decompose_missing_returns_none() {
+        let gs = GateSet::universal();
+        assert!(gs.decompose("cx").is_none());
+    }
 }
@@ -12,6 +12,7 @@
 //! | **Auth** | `AuthenticationFailed` | Re-authenticate |
 //! | **Config** | `Configuration`, `Backend` | Fix configuration |
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Errors that can occur in HAL operations.
@@ -84,6 +85,99 @@ impl HalError {
     pub fn is_transient(&self) -> bool {
         matches!(self, Self::BackendUnavailable(_) | Self::Timeout(_))
     }
+
+    /// Stable, machine-readable identifier for this error variant.
+    ///
+    /// Unlike `Display`, this is safe to match on programmatically across
+    /// a network boundary (e.g. a gateway translating backend errors to
+    /// clients) without string-matching human-readable text.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::BackendUnavailable(_) => ErrorCode::BackendUnavailable,
+            Self::Timeout(_) => ErrorCode::Timeout,
+            Self::InvalidCircuit(_) => ErrorCode::InvalidCircuit,
+            Self::CircuitTooLarge(_) => ErrorCode::CircuitTooLarge,
+            Self::InvalidShots(_) => ErrorCode::InvalidShots,
+            Self::Unsupported(_) => ErrorCode::Unsupported,
+            Self::SubmissionFailed(_) => ErrorCode::SubmissionFailed,
+            Self::JobFailed(_) => ErrorCode::JobFailed,
+            Self::JobCancelled => ErrorCode::JobCancelled,
+            Self::JobNotFound(_) => ErrorCode::JobNotFound,
+            Self::AuthenticationFailed(_) => ErrorCode::AuthenticationFailed,
+            Self::Configuration(_) => ErrorCode::Configuration,
+            Self::Backend(_) => ErrorCode::Backend,
+        }
+    }
+
+    /// The recoverability bucket this error falls into, per the table above.
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::BackendUnavailable(_) | Self::Timeout(_) => ErrorCategory::Transient,
+            Self::InvalidCircuit(_)
+            | Self::CircuitTooLarge(_)
+            | Self::InvalidShots(_)
+            | Self::Unsupported(_) => ErrorCategory::Permanent,
+            Self::SubmissionFailed(_) | Self::JobFailed(_) | Self::JobCancelled | Self::JobNotFound(_) => {
+                ErrorCategory::JobLevel
+            }
+            Self::AuthenticationFailed(_) => ErrorCategory::Auth,
+            Self::Configuration(_) | Self::Backend(_) => ErrorCategory::Config,
+        }
+    }
+}
+
+/// Stable, machine-readable identifier for a [`HalError`] variant.
+///
+/// Serializes as its kebab-case code (e.g. `"backend-unavailable"`), so
+/// errors can round-trip in JSON APIs without string-matching `Display`
+/// output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorCode {
+    /// See [`HalError::BackendUnavailable`].
+    BackendUnavailable,
+    /// See [`HalError::Timeout`].
+    Timeout,
+    /// See [`HalError::InvalidCircuit`].
+    InvalidCircuit,
+    /// See [`HalError::CircuitTooLarge`].
+    CircuitTooLarge,
+    /// See [`HalError::InvalidShots`].
+    InvalidShots,
+    /// See [`HalError::Unsupported`].
+    Unsupported,
+    /// See [`HalError::SubmissionFailed`].
+    SubmissionFailed,
+    /// See [`HalError::JobFailed`].
+    JobFailed,
+    /// See [`HalError::JobCancelled`].
+    JobCancelled,
+    /// See [`HalError::JobNotFound`].
+    JobNotFound,
+    /// See [`HalError::AuthenticationFailed`].
+    AuthenticationFailed,
+    /// See [`HalError::Configuration`].
+    Configuration,
+    /// See [`HalError::Backend`].
+    Backend,
+}
+
+/// Recoverability bucket for a [`HalError`], per [`HalError::category`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorCategory {
+    /// Retry with backoff — see [`HalError::is_transient`].
+    Transient,
+    /// Fix input; retrying as-is will not help.
+    Permanent,
+    /// Resubmit or abort the job.
+    JobLevel,
+    /// Re-authenticate.
+    Auth,
+    /// Fix configuration.
+    Config,
 }
 
 /// Result type for HAL operations.
@@ -106,4 +200,37 @@ mod tests {
         let err = HalError::InvalidCircuit("too many qubits".into());
         assert_eq!(err.to_string(), "Invalid circuit: too many qubits");
     }
+
+    #[test]
+    fn test_error_code_stable_identifiers() {
+        assert_eq!(
+            HalError::BackendUnavailable("offline".into()).code(),
+            ErrorCode::BackendUnavailable
+        );
+        assert_eq!(HalError::JobCancelled.code(), ErrorCode::JobCancelled);
+    }
+
+    #[test]
+    fn test_error_category_buckets() {
+        assert_eq!(
+            HalError::Timeout("job-1".into()).category(),
+            ErrorCategory::Transient
+        );
+        assert_eq!(
+            HalError::InvalidCircuit("bad".into()).category(),
+            ErrorCategory::Permanent
+        );
+        assert_eq!(HalError::JobNotFound("x".into()).category(), ErrorCategory::JobLevel);
+        assert_eq!(
+            HalError::AuthenticationFailed("x".into()).category(),
+            ErrorCategory::Auth
+        );
+        assert_eq!(HalError::Configuration("x".into()).category(), ErrorCategory::Config);
+    }
+
+    #[test]
+    fn test_error_code_serializes_as_kebab_case() {
+        let json = serde_json::to_string(&ErrorCode::BackendUnavailable).unwrap();
+        assert_eq!(json, "\"backend-unavailable\"");
+    }
 }
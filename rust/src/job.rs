@@ -84,6 +84,55 @@ impl JobStatus {
     }
 }
 
+/// Durable handle to a submitted job.
+///
+/// A bare [`JobId`] is enough to poll a backend that's still in scope,
+/// but a process that restarts loses that scope entirely. `JobHandle`
+/// pairs the `JobId` with the backend it was submitted to, when, and at
+/// how many shots, and round-trips through JSON via
+/// [`to_json`](JobHandle::to_json) / [`from_json`](JobHandle::from_json)
+/// so a caller can persist it (a file, a database row) and reconnect —
+/// look up the matching backend by `backend_name`, then call
+/// [`Backend::reattach`](crate::backend::Backend::reattach) to resume
+/// `status`/`wait`/`result` calls against `job_id` — after a restart.
+/// [`JobRegistry`](crate::job_registry::JobRegistry) tracks a whole set
+/// of these across a restart rather than one at a time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobHandle {
+    /// The job this handle refers to.
+    pub job_id: JobId,
+    /// `Backend::name()` of the backend the job was submitted to, so a
+    /// caller holding several backends can route the resumed handle to
+    /// the right one.
+    pub backend_name: String,
+    /// Unix timestamp (seconds) the job was submitted at.
+    pub submitted_at_unix: u64,
+    /// Number of shots the job was submitted with.
+    pub shots: u32,
+}
+
+impl JobHandle {
+    /// Create a handle for a freshly submitted job.
+    pub fn new(job_id: JobId, backend_name: impl Into<String>, submitted_at_unix: u64, shots: u32) -> Self {
+        Self {
+            job_id,
+            backend_name: backend_name.into(),
+            submitted_at_unix,
+            shots,
+        }
+    }
+
+    /// Serialize to a JSON string, for persisting across a process restart.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Parse a handle previously produced by [`to_json`](JobHandle::to_json).
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+}
+
 impl std::fmt::Display for JobStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -125,4 +174,19 @@ mod tests {
         assert_eq!(id.0, "job-123");
         assert_eq!(id.to_string(), "job-123");
     }
+
+    #[test]
+    fn test_job_handle_json_round_trips() {
+        let handle = JobHandle::new(JobId::new("job-1"), "mock-simulator", 1_700_000_000, 1000);
+
+        let json = handle.to_json().unwrap();
+        let parsed = JobHandle::from_json(&json).unwrap();
+
+        assert_eq!(parsed, handle);
+    }
+
+    #[test]
+    fn test_job_handle_from_json_rejects_garbage() {
+        assert!(JobHandle::from_json("not json").is_err());
+    }
 }
@@ -0,0 +1,270 @@
+//! Durable tracking of outstanding [`JobHandle`]s across a process restart.
+//!
+//! A [`JobHandle`] on its own still has to live somewhere a restarted
+//! process can find it. `JobRegistry` is the extension point for that:
+//! a scheduler records every `submit()` as a handle via
+//! [`track`](JobRegistry::track), and on boot calls
+//! [`reload`](JobRegistry::reload) to recover every handle still
+//! outstanding and rejoin those jobs via
+//! [`Backend::reattach`](crate::backend::Backend::reattach). Storage is
+//! delegated to a [`HandleStore`] rather than owned directly — the same
+//! pattern [`JobStore`](crate::job_store::JobStore) uses for job
+//! lifecycle state — so a downstream crate can swap in its own store
+//! without touching `JobRegistry` itself.
+//!
+//! [`InMemoryHandleStore`] is the restart-unsafe default. [`FileHandleStore`]
+//! is the durable option for a single-process scheduler that doesn't want
+//! a database dependency; it keeps the whole handle set as one JSON file,
+//! rewritten in full on every write, so it is not meant for high write
+//! volume or for sharing across concurrent processes.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::error::{HalError, HalResult};
+use crate::job::{JobHandle, JobId};
+
+/// Pluggable storage for the set of [`JobHandle`]s a [`JobRegistry`]
+/// tracks.
+#[async_trait]
+pub trait HandleStore: Send + Sync {
+    /// Persist `handle`, replacing any previously stored handle for the
+    /// same `job_id`.
+    async fn save(&self, handle: JobHandle) -> HalResult<()>;
+
+    /// Remove the handle for `job_id`, if one is stored. Not an error if
+    /// none was stored — callers untrack jobs they may have already
+    /// untracked, e.g. during shutdown cleanup.
+    async fn remove(&self, job_id: &JobId) -> HalResult<()>;
+
+    /// Load every handle currently stored, in no particular order.
+    async fn load_all(&self) -> HalResult<Vec<JobHandle>>;
+}
+
+/// Default in-memory [`HandleStore`]. Handles do not survive process
+/// restart — useful for tests, or as a placeholder before wiring up
+/// [`FileHandleStore`] or a downstream crate's own store.
+#[derive(Default)]
+pub struct InMemoryHandleStore {
+    handles: Mutex<HashMap<String, JobHandle>>,
+}
+
+impl InMemoryHandleStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl HandleStore for InMemoryHandleStore {
+    async fn save(&self, handle: JobHandle) -> HalResult<()> {
+        self.handles.lock().unwrap().insert(handle.job_id.0.clone(), handle);
+        Ok(())
+    }
+
+    async fn remove(&self, job_id: &JobId) -> HalResult<()> {
+        self.handles.lock().unwrap().remove(&job_id.0);
+        Ok(())
+    }
+
+    async fn load_all(&self) -> HalResult<Vec<JobHandle>> {
+        Ok(self.handles.lock().unwrap().values().cloned().collect())
+    }
+}
+
+/// File-backed [`HandleStore`]: the whole handle set as one JSON array at
+/// a fixed path, rewritten in full on every `save`/`remove`.
+pub struct FileHandleStore {
+    path: PathBuf,
+}
+
+impl FileHandleStore {
+    /// Use `path` as the backing file. The file is created on the first
+    /// write; it does not need to exist yet, and a missing file reads
+    /// back as an empty handle set.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn read_all(&self) -> HalResult<Vec<JobHandle>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(contents) if contents.trim().is_empty() => Ok(Vec::new()),
+            Ok(contents) => serde_json::from_str(&contents).map_err(|err| {
+                HalError::Configuration(format!("corrupt handle store at {}: {err}", self.path.display()))
+            }),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(HalError::Configuration(format!(
+                "reading handle store at {}: {err}",
+                self.path.display()
+            ))),
+        }
+    }
+
+    fn write_all(&self, handles: &[JobHandle]) -> HalResult<()> {
+        let json = serde_json::to_string_pretty(handles)
+            .map_err(|err| HalError::Configuration(format!("serializing handle store: {err}")))?;
+        std::fs::write(&self.path, json).map_err(|err| {
+            HalError::Configuration(format!("writing handle store at {}: {err}", self.path.display()))
+        })
+    }
+}
+
+#[async_trait]
+impl HandleStore for FileHandleStore {
+    async fn save(&self, handle: JobHandle) -> HalResult<()> {
+        let mut handles = self.read_all()?;
+        handles.retain(|existing| existing.job_id != handle.job_id);
+        handles.push(handle);
+        self.write_all(&handles)
+    }
+
+    async fn remove(&self, job_id: &JobId) -> HalResult<()> {
+        let mut handles = self.read_all()?;
+        handles.retain(|existing| &existing.job_id != job_id);
+        self.write_all(&handles)
+    }
+
+    async fn load_all(&self) -> HalResult<Vec<JobHandle>> {
+        self.read_all()
+    }
+}
+
+/// Tracks every outstanding [`JobHandle`] a scheduler has submitted, so it
+/// can reload and rejoin all of them after a restart instead of orphaning
+/// in-flight jobs.
+///
+/// Generic over [`HandleStore`] so the backing medium is a
+/// construction-time choice rather than baked into the registry's API.
+pub struct JobRegistry<S: HandleStore> {
+    store: S,
+}
+
+impl<S: HandleStore> JobRegistry<S> {
+    /// Wrap `store` in a registry.
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    /// Record a freshly submitted job so it can be reloaded later.
+    pub async fn track(&self, handle: JobHandle) -> HalResult<()> {
+        self.store.save(handle).await
+    }
+
+    /// Stop tracking `job_id` — call once it reaches a terminal status and
+    /// reattachment is no longer needed.
+    pub async fn untrack(&self, job_id: &JobId) -> HalResult<()> {
+        self.store.remove(job_id).await
+    }
+
+    /// Reload every handle still tracked, for rejoining outstanding jobs
+    /// on boot via [`Backend::reattach`](crate::backend::Backend::reattach).
+    pub async fn reload(&self) -> HalResult<Vec<JobHandle>> {
+        self.store.load_all().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handle(job_id: &str) -> JobHandle {
+        JobHandle::new(JobId::new(job_id), "mock-simulator", 1_700_000_000, 1000)
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_registry_tracks_and_reloads() {
+        let registry = JobRegistry::new(InMemoryHandleStore::new());
+        registry.track(handle("job-1")).await.unwrap();
+        registry.track(handle("job-2")).await.unwrap();
+
+        let mut reloaded: Vec<_> = registry.reload().await.unwrap().into_iter().map(|h| h.job_id.0).collect();
+        reloaded.sort();
+        assert_eq!(reloaded, vec!["job-1".to_string(), "job-2".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_registry_untrack_removes_handle() {
+        let registry = JobRegistry::new(InMemoryHandleStore::new());
+        registry.track(handle("job-1")).await.unwrap();
+        registry.untrack(&JobId::new("job-1")).await.unwrap();
+
+        assert!(registry.reload().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_registry_untrack_unknown_job_is_noop() {
+        let registry = JobRegistry::new(InMemoryHandleStore::new());
+        registry.untrack(&JobId::new("missing")).await.unwrap();
+        assert!(registry.reload().await.unwrap().is_empty());
+    }
+
+    fn temp_store_path(label: &str) -> PathBuf {
+        static COUNTER: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+        let n = COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        std::env::temp_dir().join(format!("hal-contract-test-registry-{label}-{}-{n}.json", std::process::id()))
+    }
+
+    #[tokio::test]
+    async fn test_file_registry_survives_reconstruction() {
+        let path = temp_store_path("survives");
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let registry = JobRegistry::new(FileHandleStore::new(&path));
+            registry.track(handle("job-1")).await.unwrap();
+            registry.track(handle("job-2")).await.unwrap();
+        }
+
+        let reloaded_registry = JobRegistry::new(FileHandleStore::new(&path));
+        let mut reloaded: Vec<_> =
+            reloaded_registry.reload().await.unwrap().into_iter().map(|h| h.job_id.0).collect();
+        reloaded.sort();
+        assert_eq!(reloaded, vec!["job-1".to_string(), "job-2".to_string()]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_file_registry_missing_file_reads_as_empty() {
+        let path = temp_store_path("missing");
+        let _ = std::fs::remove_file(&path);
+
+        let registry = JobRegistry::new(FileHandleStore::new(&path));
+        assert!(registry.reload().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_file_registry_untrack_removes_handle_on_disk() {
+        let path = temp_store_path("untrack");
+        let _ = std::fs::remove_file(&path);
+
+        let registry = JobRegistry::new(FileHandleStore::new(&path));
+        registry.track(handle("job-1")).await.unwrap();
+        registry.untrack(&JobId::new("job-1")).await.unwrap();
+
+        assert!(registry.reload().await.unwrap().is_empty());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_file_registry_save_replaces_existing_handle_for_same_job() {
+        let path = temp_store_path("replace");
+        let _ = std::fs::remove_file(&path);
+
+        let registry = JobRegistry::new(FileHandleStore::new(&path));
+        registry.track(handle("job-1")).await.unwrap();
+        registry
+            .track(JobHandle::new(JobId::new("job-1"), "mock-simulator", 1_700_000_500, 2000))
+            .await
+            .unwrap();
+
+        let reloaded = registry.reload().await.unwrap();
+        assert_eq!(reloaded.len(), 1);
+        assert_eq!(reloaded[0].shots, 2000);
+        let _ = std::fs::remove_file(&path);
+    }
+}
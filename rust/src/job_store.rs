@@ -0,0 +1,273 @@
+//! Pluggable persistence for job lifecycle state.
+//!
+//! Backends built directly on an in-process `Mutex<HashMap<..>>` lose all
+//! job state on restart. `JobStore` is the extension point: a backend
+//! delegates lifecycle bookkeeping to a store instead of owning it, so a
+//! downstream crate can back it with sled, Postgres, or anything else
+//! without touching the `Backend` impl. Modeled on the `background-jobs`
+//! crate's `Storage` trait — `submit_record`/`heartbeat`/`update_status`
+//! map to its `push`/`heartbeat`/`complete`.
+//!
+//! [`InMemoryJobStore`] is the default, restart-unsafe implementation —
+//! the same behavior the mock backend had before, just factored out.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use async_trait::async_trait;
+
+use crate::job::{JobId, JobStatus};
+use crate::result::ExecutionResult;
+
+/// Backend-supplied metadata recorded alongside a job at submission time.
+///
+/// Kept intentionally opaque to the store — it doesn't need to understand
+/// a specific circuit IR, only enough to describe or resubmit the job.
+#[derive(Debug, Clone, Default)]
+pub struct JobMetadata {
+    /// Free-form description (e.g. circuit name or hash).
+    pub description: Option<String>,
+    /// Number of shots requested at submission.
+    pub shots: Option<u32>,
+}
+
+/// Pluggable storage for job lifecycle state.
+///
+/// Implementations MUST be safe to share across the async runtime, since a
+/// `Backend` typically holds one store behind `&self`.
+#[async_trait]
+pub trait JobStore: Send + Sync {
+    /// Record a newly submitted job. MUST be called before any other
+    /// method observes `job_id`.
+    async fn submit_record(&self, job_id: JobId, metadata: JobMetadata);
+
+    /// Update a job's status, optionally attaching its result (normally
+    /// only present when transitioning to `Completed`).
+    async fn update_status(&self, job_id: &JobId, status: JobStatus, result: Option<ExecutionResult>);
+
+    /// Atomically move a job to `Cancelled` unless it has already reached a
+    /// terminal status. Returns the status observed at the time of the
+    /// attempt, so callers can tell a successful cancel apart from a job
+    /// that had already completed or failed.
+    ///
+    /// This exists so `cancel()` implementations don't have to do their own
+    /// load-then-update, which races against a concurrent status update
+    /// between the two calls and can stomp a terminal `Completed`/`Failed`
+    /// with `Cancelled`.
+    async fn cancel_if_not_terminal(&self, job_id: &JobId) -> Option<JobStatus>;
+
+    /// Load a job's current status and result, if the job is known.
+    async fn load(&self, job_id: &JobId) -> Option<(JobStatus, Option<ExecutionResult>)>;
+
+    /// Load the metadata recorded at submission time, if the job is known.
+    async fn metadata(&self, job_id: &JobId) -> Option<JobMetadata>;
+
+    /// Record that `runner_id` is actively working `job_id`. Long-running
+    /// submissions with no recent heartbeat can be treated as stale and
+    /// resubmitted — the state machine in [`JobStatus`] has no way to
+    /// express that on its own.
+    async fn heartbeat(&self, job_id: &JobId, runner_id: &str);
+
+    /// The runner and time of the most recent heartbeat for `job_id`, if
+    /// any has been recorded.
+    async fn last_heartbeat(&self, job_id: &JobId) -> Option<(String, Instant)>;
+}
+
+struct JobRecord {
+    metadata: JobMetadata,
+    status: JobStatus,
+    result: Option<ExecutionResult>,
+    last_heartbeat: Option<(String, Instant)>,
+}
+
+/// Default in-memory [`JobStore`]. Job state does not survive process
+/// restart — this is a direct factoring-out of the mock backend's
+/// original `Mutex<HashMap<..>>`, not a durability guarantee.
+#[derive(Default)]
+pub struct InMemoryJobStore {
+    jobs: Mutex<HashMap<String, JobRecord>>,
+}
+
+impl InMemoryJobStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl JobStore for InMemoryJobStore {
+    async fn submit_record(&self, job_id: JobId, metadata: JobMetadata) {
+        self.jobs.lock().unwrap().insert(
+            job_id.0,
+            JobRecord {
+                metadata,
+                status: JobStatus::Queued,
+                result: None,
+                last_heartbeat: None,
+            },
+        );
+    }
+
+    async fn update_status(&self, job_id: &JobId, status: JobStatus, result: Option<ExecutionResult>) {
+        if let Some(record) = self.jobs.lock().unwrap().get_mut(&job_id.0) {
+            record.status = status;
+            if result.is_some() {
+                record.result = result;
+            }
+        }
+    }
+
+    async fn load(&self, job_id: &JobId) -> Option<(JobStatus, Option<ExecutionResult>)> {
+        self.jobs
+            .lock()
+            .unwrap()
+            .get(&job_id.0)
+            .map(|record| (record.status.clone(), record.result.clone()))
+    }
+
+    async fn cancel_if_not_terminal(&self, job_id: &JobId) -> Option<JobStatus> {
+        let mut jobs = self.jobs.lock().unwrap();
+        let record = jobs.get_mut(&job_id.0)?;
+        let observed = record.status.clone();
+        if !observed.is_terminal() {
+            record.status = JobStatus::Cancelled;
+        }
+        Some(observed)
+    }
+
+    async fn metadata(&self, job_id: &JobId) -> Option<JobMetadata> {
+        self.jobs.lock().unwrap().get(&job_id.0).map(|record| record.metadata.clone())
+    }
+
+    async fn heartbeat(&self, job_id: &JobId, runner_id: &str) {
+        if let Some(record) = self.jobs.lock().unwrap().get_mut(&job_id.0) {
+            record.last_heartbeat = Some((runner_id.to_string(), Instant::now()));
+        }
+    }
+
+    async fn last_heartbeat(&self, job_id: &JobId) -> Option<(String, Instant)> {
+        self.jobs
+            .lock()
+            .unwrap()
+            .get(&job_id.0)
+            .and_then(|record| record.last_heartbeat.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_submit_and_load_round_trip() {
+        let store = InMemoryJobStore::new();
+        let id = JobId::new("job-1");
+        store
+            .submit_record(
+                id.clone(),
+                JobMetadata {
+                    description: Some("bell-pair".into()),
+                    shots: Some(1000),
+                },
+            )
+            .await;
+
+        let (status, result) = store.load(&id).await.unwrap();
+        assert_eq!(status, JobStatus::Queued);
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_update_status_attaches_result_on_completion() {
+        let store = InMemoryJobStore::new();
+        let id = JobId::new("job-1");
+        store.submit_record(id.clone(), JobMetadata::default()).await;
+
+        let result = ExecutionResult::new(crate::result::Counts::new(), 100);
+        store
+            .update_status(&id, JobStatus::Completed, Some(result))
+            .await;
+
+        let (status, result) = store.load(&id).await.unwrap();
+        assert_eq!(status, JobStatus::Completed);
+        assert!(result.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_metadata_round_trips() {
+        let store = InMemoryJobStore::new();
+        let id = JobId::new("job-1");
+        store
+            .submit_record(
+                id.clone(),
+                JobMetadata {
+                    description: Some("bell-pair".into()),
+                    shots: Some(1000),
+                },
+            )
+            .await;
+
+        let metadata = store.metadata(&id).await.unwrap();
+        assert_eq!(metadata.description.as_deref(), Some("bell-pair"));
+        assert_eq!(metadata.shots, Some(1000));
+    }
+
+    #[tokio::test]
+    async fn test_load_unknown_job_returns_none() {
+        let store = InMemoryJobStore::new();
+        assert!(store.load(&JobId::new("missing")).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_recorded_and_queryable() {
+        let store = InMemoryJobStore::new();
+        let id = JobId::new("job-1");
+        store.submit_record(id.clone(), JobMetadata::default()).await;
+        store.heartbeat(&id, "runner-a").await;
+
+        let (runner_id, _when) = store.last_heartbeat(&id).await.unwrap();
+        assert_eq!(runner_id, "runner-a");
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_for_unknown_job_is_noop() {
+        let store = InMemoryJobStore::new();
+        store.heartbeat(&JobId::new("missing"), "runner-a").await;
+        assert!(store.last_heartbeat(&JobId::new("missing")).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_if_not_terminal_cancels_pending_job() {
+        let store = InMemoryJobStore::new();
+        let id = JobId::new("job-1");
+        store.submit_record(id.clone(), JobMetadata::default()).await;
+
+        let observed = store.cancel_if_not_terminal(&id).await;
+        assert_eq!(observed, Some(JobStatus::Queued));
+
+        let (status, _) = store.load(&id).await.unwrap();
+        assert_eq!(status, JobStatus::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_if_not_terminal_leaves_completed_job_alone() {
+        let store = InMemoryJobStore::new();
+        let id = JobId::new("job-1");
+        store.submit_record(id.clone(), JobMetadata::default()).await;
+        store.update_status(&id, JobStatus::Completed, None).await;
+
+        let observed = store.cancel_if_not_terminal(&id).await;
+        assert_eq!(observed, Some(JobStatus::Completed));
+
+        let (status, _) = store.load(&id).await.unwrap();
+        assert_eq!(status, JobStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_if_not_terminal_for_unknown_job_returns_none() {
+        let store = InMemoryJobStore::new();
+        assert!(store.cancel_if_not_terminal(&JobId::new("missing")).await.is_none());
+    }
+}
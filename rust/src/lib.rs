@@ -48,10 +48,20 @@ pub mod backend;
 pub mod capability;
 pub mod error;
 pub mod job;
+pub mod job_registry;
+pub mod job_store;
+pub mod noise;
 pub mod result;
+pub mod retry;
+pub mod wait;
 
-pub use backend::{Backend, BackendAvailability, ValidationResult};
-pub use capability::{Capabilities, GateSet, NoiseProfile, Topology, TopologyKind};
-pub use error::{HalError, HalResult};
-pub use job::{JobId, JobStatus};
-pub use result::{Counts, ExecutionResult};
+pub use backend::{Backend, BackendAvailability, BatchWaitMode, ResultStream, StatusStream, ValidationResult};
+pub use capability::{Capabilities, GateSet, NoiseProfile, PathStep, Topology, TopologyKind};
+pub use error::{ErrorCategory, ErrorCode, HalError, HalResult};
+pub use job::{JobHandle, JobId, JobStatus};
+pub use job_registry::{FileHandleStore, HandleStore, InMemoryHandleStore, JobRegistry};
+pub use job_store::{InMemoryJobStore, JobMetadata, JobStore};
+pub use noise::{GateClass, NoiseChannelSpec, PauliChannel};
+pub use result::{Counts, ExecutionResult, ResultChunk};
+pub use retry::{execute_with_retry, Backoff, MaxRetries, RetryPolicy, RetryableCall};
+pub use wait::{wait_for_terminal, CancelSwitch, PollEvent, PollSchedule, WaitOptions, WaitWarning};
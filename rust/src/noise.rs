@@ -0,0 +1,173 @@
+//! Parametric noise channels for noisy shot-based simulation.
+//!
+//! [`NoiseProfile`](crate::capability::NoiseProfile) describes noise as
+//! *measured* on real hardware. The types here describe noise a simulator
+//! should *inject*: a [`PauliChannel`] applied after each gate (and before
+//! each measurement) with some probability, following the Q# sparse
+//! simulator's noise model.
+
+use serde::{Deserialize, Serialize};
+
+use crate::capability::Capabilities;
+
+/// A Pauli noise channel: X, Y, or Z is applied independently with the
+/// given probability.
+///
+/// Probabilities are each in `[0.0, 1.0]` and are independent (not
+/// mutually exclusive) — e.g. `px = py = 1.0` applies both X and Y.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PauliChannel {
+    /// Probability of an X error.
+    pub px: f64,
+    /// Probability of a Y error.
+    pub py: f64,
+    /// Probability of a Z error.
+    pub pz: f64,
+}
+
+impl PauliChannel {
+    /// A channel with no error probability at all.
+    pub fn none() -> Self {
+        Self {
+            px: 0.0,
+            py: 0.0,
+            pz: 0.0,
+        }
+    }
+
+    /// Bit-flip (X) channel with probability `p`.
+    pub fn bit_flip(p: f64) -> Self {
+        Self {
+            px: p,
+            py: 0.0,
+            pz: 0.0,
+        }
+    }
+
+    /// Phase-flip (Z) channel with probability `p`.
+    pub fn phase_flip(p: f64) -> Self {
+        Self {
+            px: 0.0,
+            py: 0.0,
+            pz: p,
+        }
+    }
+
+    /// Depolarizing channel with depolarizing parameter `p`: X, Y, and Z
+    /// each get probability `p / 4` (`px = py = pz = p / 4`), so the three
+    /// probabilities sum to `3p / 4`, not `p`.
+    pub fn depolarizing(p: f64) -> Self {
+        let share = p / 4.0;
+        Self {
+            px: share,
+            py: share,
+            pz: share,
+        }
+    }
+}
+
+/// Which gates a [`NoiseChannelSpec`] applies to.
+///
+/// Idle/identity and qubit allocation are never covered by a
+/// `GateClass` — they remain noiseless.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum GateClass {
+    /// Single-qubit gates.
+    SingleQubit,
+    /// Two-qubit gates. The channel is applied independently to each
+    /// operand qubit.
+    TwoQubit,
+    /// Qubit reset.
+    Reset,
+    /// Measurement / readout.
+    Measurement,
+}
+
+/// A noise channel attached to a class of operations.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NoiseChannelSpec {
+    /// Which operations this channel applies to.
+    pub applies_to: GateClass,
+    /// The Pauli channel to apply.
+    pub channel: PauliChannel,
+}
+
+impl Capabilities {
+    /// Attach a uniform depolarizing noise model (probability `p`) to
+    /// both single- and two-qubit gates.
+    ///
+    /// For two-qubit gates the channel is applied independently to each
+    /// operand qubit, per [`GateClass::TwoQubit`]. Idle/identity and
+    /// allocation remain noiseless.
+    pub fn with_depolarizing(mut self, p: f64) -> Self {
+        let channel = PauliChannel::depolarizing(p);
+        self.noise_channels.get_or_insert_with(Vec::new).extend([
+            NoiseChannelSpec {
+                applies_to: GateClass::SingleQubit,
+                channel,
+            },
+            NoiseChannelSpec {
+                applies_to: GateClass::TwoQubit,
+                channel,
+            },
+        ]);
+        self
+    }
+
+    /// Attach a single noise channel for a specific [`GateClass`].
+    pub fn with_noise_channel(mut self, applies_to: GateClass, channel: PauliChannel) -> Self {
+        self.noise_channels
+            .get_or_insert_with(Vec::new)
+            .push(NoiseChannelSpec { applies_to, channel });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pauli_channel_constructors() {
+        assert_eq!(
+            PauliChannel::bit_flip(0.1),
+            PauliChannel {
+                px: 0.1,
+                py: 0.0,
+                pz: 0.0
+            }
+        );
+        assert_eq!(
+            PauliChannel::phase_flip(0.2),
+            PauliChannel {
+                px: 0.0,
+                py: 0.0,
+                pz: 0.2
+            }
+        );
+        let depol = PauliChannel::depolarizing(0.04);
+        assert!((depol.px - 0.01).abs() < 1e-12);
+        assert!((depol.py - 0.01).abs() < 1e-12);
+        assert!((depol.pz - 0.01).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_capabilities_with_depolarizing() {
+        let caps = Capabilities::simulator(4).with_depolarizing(0.01);
+        let channels = caps.noise_channels.as_ref().unwrap();
+        assert_eq!(channels.len(), 2);
+        assert!(channels
+            .iter()
+            .any(|c| c.applies_to == GateClass::SingleQubit));
+        assert!(channels.iter().any(|c| c.applies_to == GateClass::TwoQubit));
+    }
+
+    #[test]
+    fn test_capabilities_with_noise_channel_accumulates() {
+        let caps = Capabilities::simulator(4)
+            .with_noise_channel(GateClass::Reset, PauliChannel::bit_flip(0.001))
+            .with_noise_channel(GateClass::Measurement, PauliChannel::bit_flip(0.02));
+        assert_eq!(caps.noise_channels.as_ref().unwrap().len(), 2);
+    }
+}
@@ -7,9 +7,33 @@
 //! the string `"01"` means qubit 0 measured `1` and qubit 1
 //! measured `0`.
 
+use std::ops::AddAssign;
+
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
+/// Value of qubit `qubit` within `bitstring`, per the rightmost-bit =
+/// qubit-0 convention. `None` if `bitstring` is too short to contain it.
+fn qubit_bit(bitstring: &str, qubit: usize) -> Option<char> {
+    let bytes = bitstring.as_bytes();
+    let len = bytes.len();
+    if qubit >= len {
+        return None;
+    }
+    Some(bytes[len - 1 - qubit] as char)
+}
+
+/// Build the bitstring for a subset (or permutation) of qubits: the
+/// resulting string's qubit `i` (per the usual rightmost-bit = qubit-0
+/// convention) is `bitstring`'s qubit `qubits[i]`. Bitstrings too short
+/// to contain every requested qubit are dropped (`None`).
+fn select_qubits(bitstring: &str, qubits: &[usize]) -> Option<String> {
+    let new_len = qubits.len();
+    (0..new_len)
+        .map(|position| qubit_bit(bitstring, qubits[new_len - 1 - position]))
+        .collect()
+}
+
 /// Measurement counts from circuit execution.
 ///
 /// Maps bitstrings to occurrence counts. Bitstring ordering follows
@@ -91,6 +115,101 @@ impl Counts {
     pub fn is_empty(&self) -> bool {
         self.counts.is_empty()
     }
+
+    /// Sum counts over all qubits *not* in `qubits`, keeping only the
+    /// given subset (in the given order — `qubits[0]` becomes qubit 0 of
+    /// the result, etc). Bitstrings shorter than required to contain
+    /// every requested qubit are dropped.
+    pub fn marginal(&self, qubits: &[usize]) -> Counts {
+        let mut result = Counts::new();
+        for (bitstring, &count) in &self.counts {
+            if let Some(key) = select_qubits(bitstring, qubits) {
+                result.insert(key, count);
+            }
+        }
+        result
+    }
+
+    /// Permute bit positions, e.g. to remap measured qubits to logical
+    /// order, with `qubits` a permutation of `0..bitstring.len()`. This is
+    /// [`Counts::marginal`] under another name and follows its remapping
+    /// rule: `qubits` is not checked to be a permutation — a subset sums
+    /// over the missing qubits, a repeated index repeats that bit — and
+    /// only bitstrings too short to contain every index are dropped.
+    pub fn reorder(&self, qubits: &[usize]) -> Counts {
+        self.marginal(qubits)
+    }
+
+    /// Accumulate counts from `other`, e.g. to combine partial results
+    /// from independent shot batches run on different workers.
+    pub fn merge(&mut self, other: &Counts) {
+        for (bitstring, &count) in &other.counts {
+            self.insert(bitstring.clone(), count);
+        }
+    }
+
+    /// Expectation value of a tensor product of Pauli-Z on `qubits`:
+    /// `sum over bitstrings of count * (-1)^(popcount of selected bits) / total_shots`.
+    /// Bitstrings too short to contain every requested qubit are excluded
+    /// from both the sum and `total_shots`, consistent with [`Counts::marginal`].
+    #[allow(clippy::cast_precision_loss)]
+    pub fn expectation_z(&self, qubits: &[usize]) -> f64 {
+        let mut weighted_sum = 0.0;
+        let mut total = 0u64;
+        for (bitstring, &count) in &self.counts {
+            let Some(parity) = qubits
+                .iter()
+                .try_fold(0u32, |acc, &q| qubit_bit(bitstring, q).map(|bit| acc + u32::from(bit == '1')))
+            else {
+                continue;
+            };
+            let sign = if parity % 2 == 0 { 1.0 } else { -1.0 };
+            weighted_sum += sign * count as f64;
+            total += count;
+        }
+        if total == 0 {
+            return 0.0;
+        }
+        weighted_sum / total as f64
+    }
+}
+
+impl AddAssign<&Counts> for Counts {
+    /// Equivalent to [`Counts::merge`].
+    fn add_assign(&mut self, other: &Counts) {
+        self.merge(other);
+    }
+}
+
+/// A partial result delivered by [`Backend::result_stream`](crate::backend::Backend::result_stream)
+/// before a job reaches `Completed`.
+///
+/// `counts` is a delta (the shots observed since the previous chunk), not
+/// cumulative — fold chunks into a running total with [`Counts::merge`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultChunk {
+    /// Counts observed since the previous chunk.
+    pub counts: Counts,
+    /// Shots completed so far, including this chunk.
+    pub shots_so_far: u32,
+    /// Total shots requested for the job.
+    pub shots_total: u32,
+}
+
+impl ResultChunk {
+    /// Create a new chunk.
+    pub fn new(counts: Counts, shots_so_far: u32, shots_total: u32) -> Self {
+        Self {
+            counts,
+            shots_so_far,
+            shots_total,
+        }
+    }
+
+    /// Whether this chunk completes the job's requested shot count.
+    pub fn is_final(&self) -> bool {
+        self.shots_so_far >= self.shots_total
+    }
 }
 
 impl FromIterator<(String, u64)> for Counts {
@@ -204,6 +323,88 @@ mod tests {
         assert_eq!(*count, 900);
     }
 
+    #[test]
+    fn test_marginal_sums_over_dropped_qubits() {
+        // 3-qubit counts; q0 is rightmost.
+        let counts = Counts::from_pairs([
+            ("000".to_string(), 10),
+            ("001".to_string(), 20),
+            ("010".to_string(), 30),
+            ("011".to_string(), 40),
+        ]);
+
+        // Keep only q0: q2q1q0 -> q0. "000"/"010" have q0=0, "001"/"011" have q0=1.
+        let marginal = counts.marginal(&[0]);
+        assert_eq!(marginal.get("0"), 40);
+        assert_eq!(marginal.get("1"), 60);
+    }
+
+    #[test]
+    fn test_marginal_drops_bitstrings_too_short() {
+        let counts = Counts::from_pairs([("0".to_string(), 5)]);
+        let marginal = counts.marginal(&[0, 1]);
+        assert!(marginal.is_empty());
+    }
+
+    #[test]
+    fn test_reorder_swaps_bit_positions() {
+        let counts = Counts::from_pairs([("10".to_string(), 100)]); // q1=1, q0=0
+        let reordered = counts.reorder(&[1, 0]); // new q0 <- old q1, new q1 <- old q0
+        assert_eq!(reordered.get("01"), 100);
+    }
+
+    #[test]
+    fn test_merge_accumulates_counts() {
+        let mut a = Counts::from_pairs([("00".to_string(), 10), ("11".to_string(), 5)]);
+        let b = Counts::from_pairs([("00".to_string(), 3), ("01".to_string(), 7)]);
+
+        a.merge(&b);
+
+        assert_eq!(a.get("00"), 13);
+        assert_eq!(a.get("01"), 7);
+        assert_eq!(a.get("11"), 5);
+    }
+
+    #[test]
+    fn test_add_assign_matches_merge() {
+        let mut a = Counts::from_pairs([("0".to_string(), 1)]);
+        let b = Counts::from_pairs([("0".to_string(), 2), ("1".to_string(), 3)]);
+
+        a += &b;
+
+        assert_eq!(a.get("0"), 3);
+        assert_eq!(a.get("1"), 3);
+    }
+
+    #[test]
+    fn test_expectation_z_all_zero_is_positive_one() {
+        let counts = Counts::from_pairs([("00".to_string(), 100)]);
+        assert!((counts.expectation_z(&[0, 1]) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_expectation_z_single_qubit_mixed() {
+        let counts = Counts::from_pairs([("0".to_string(), 300), ("1".to_string(), 700)]);
+        // +1 * 300 + -1 * 700 = -400, / 1000 = -0.4
+        assert!((counts.expectation_z(&[0]) - (-0.4)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_expectation_z_excludes_too_short_bitstrings() {
+        let counts = Counts::from_pairs([("0".to_string(), 50), ("11".to_string(), 50)]);
+        // qubit 1 only exists on "11" (parity 1 -> -1); "0" is excluded entirely.
+        assert!((counts.expectation_z(&[1]) - (-1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_result_chunk_is_final() {
+        let chunk = ResultChunk::new(Counts::new(), 500, 1000);
+        assert!(!chunk.is_final());
+
+        let chunk = ResultChunk::new(Counts::new(), 1000, 1000);
+        assert!(chunk.is_final());
+    }
+
     #[test]
     fn test_execution_result() {
         let counts = Counts::from_pairs([("00".to_string(), 500), ("11".to_string(), 500)]);
@@ -0,0 +1,324 @@
+//! Retry-with-backoff orchestration for transient [`HalError`] variants.
+//!
+//! [`HalError::is_transient`] already classifies which variants are worth
+//! retrying; this module is what actually acts on that classification.
+//! [`execute_with_retry`] wraps any fallible async call — not just
+//! `Backend` methods — in truncated exponential backoff, and
+//! [`RetryableCall`] is a thin extension on zero-argument closures, so a
+//! call reads as `(move || ...).retrying(&policy)` without each backend
+//! re-implementing the loop. [`Backend::submit_with_retry`](crate::backend::Backend::submit_with_retry)
+//! builds directly on the same [`RetryPolicy`].
+
+use std::future::Future;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rand::Rng;
+
+use crate::error::HalResult;
+
+/// How many times to retry before giving up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaxRetries {
+    /// Stop once this many retries (beyond the first attempt) have run.
+    Count(u32),
+    /// Keep retrying indefinitely.
+    Infinite,
+}
+
+impl MaxRetries {
+    /// Whether another retry numbered `n` (1-indexed) is permitted.
+    pub(crate) fn allows(&self, n: u32) -> bool {
+        match self {
+            Self::Count(max) => n <= *max,
+            Self::Infinite => true,
+        }
+    }
+}
+
+/// Delay schedule between retries, shared by [`RetryPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backoff {
+    /// Delay grows by a fixed increment each retry: `min(max, base * n)`.
+    Linear {
+        /// Delay added per retry.
+        base: Duration,
+        /// Upper bound on any single delay.
+        max: Duration,
+    },
+    /// Delay grows exponentially: `min(max, base * multiplier^(n-1))`.
+    Exponential {
+        /// Delay before the first retry.
+        base: Duration,
+        /// Multiplier applied after each retry.
+        multiplier: f64,
+        /// Upper bound on any single delay.
+        max: Duration,
+    },
+}
+
+impl Backoff {
+    /// Delay before retry `n` (1-indexed), ignoring jitter.
+    fn delay_for_attempt(&self, n: u32) -> Duration {
+        match self {
+            Self::Linear { base, max } => base.saturating_mul(n).min(*max),
+            Self::Exponential { base, multiplier, max } => {
+                let secs = base.as_secs_f64() * multiplier.powi(n as i32 - 1);
+                Duration::from_secs_f64(secs).min(*max)
+            }
+        }
+    }
+}
+
+/// Retry policy for [`execute_with_retry`] and
+/// [`Backend::submit_with_retry`](crate::backend::Backend::submit_with_retry):
+/// how many times to retry and how long to wait between attempts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// How many retries to allow before giving up.
+    pub max_retries: MaxRetries,
+    /// Delay schedule between retries.
+    pub backoff: Backoff,
+    /// Jitter fraction in `[0.0, 1.0]`; `0.0` disables jitter.
+    pub jitter: f64,
+}
+
+impl RetryPolicy {
+    /// Create a policy from an explicit retry count and backoff schedule,
+    /// with no jitter.
+    pub fn with_backoff(max_retries: MaxRetries, backoff: Backoff) -> Self {
+        Self { max_retries, backoff, jitter: 0.0 }
+    }
+
+    /// Create an exponential-backoff policy from a total attempt count
+    /// (including the first), with no jitter.
+    pub fn new(base_delay: Duration, multiplier: f64, max_delay: Duration, max_attempts: u32) -> Self {
+        Self::with_backoff(
+            MaxRetries::Count(max_attempts.max(1) - 1),
+            Backoff::Exponential { base: base_delay, multiplier, max: max_delay },
+        )
+    }
+
+    /// Set the jitter fraction (clamped to `[0.0, 1.0]`).
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Delay before retry `n` (1-indexed), including jitter.
+    pub(crate) fn delay_for_attempt(&self, n: u32) -> Duration {
+        let unjittered = self.backoff.delay_for_attempt(n).as_secs_f64();
+        let factor = if self.jitter > 0.0 {
+            rand::thread_rng().gen_range((1.0 - self.jitter)..=(1.0 + self.jitter))
+        } else {
+            1.0
+        };
+        Duration::from_secs_f64((unjittered * factor).max(0.0))
+    }
+}
+
+impl Default for RetryPolicy {
+    /// 200ms base, 2x multiplier, 30s cap, 5 total attempts, 10% jitter.
+    fn default() -> Self {
+        Self::new(Duration::from_millis(200), 2.0, Duration::from_secs(30), 5).with_jitter(0.1)
+    }
+}
+
+/// Run `f`, retrying with `policy`'s backoff schedule whenever the
+/// returned error is [transient](HalError::is_transient), up to
+/// `policy.max_retries` retries. Returns the last error once retries are
+/// exhausted or the error is non-transient.
+pub async fn execute_with_retry<T, F, Fut>(policy: &RetryPolicy, mut f: F) -> HalResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = HalResult<T>>,
+{
+    let mut retries_done: u32 = 0;
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(err) if err.is_transient() && policy.max_retries.allows(retries_done + 1) => {
+                retries_done += 1;
+                tokio::time::sleep(policy.delay_for_attempt(retries_done)).await;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Extension trait making [`execute_with_retry`] available as a method
+/// on any zero-argument async call, so a call site can read as
+/// `call.retrying(&policy).await` instead of importing the free function.
+///
+/// `retrying` requires the closure to be `Send + 'static`, so it must own
+/// what it captures (e.g. an `Arc` of the backend); a closure that
+/// borrows a local backend or circuit does not qualify — use
+/// [`execute_with_retry`] for those.
+#[async_trait]
+pub trait RetryableCall<T, Fut: Future<Output = HalResult<T>> + Send>: FnMut() -> Fut {
+    /// Run this call with [`execute_with_retry`].
+    async fn retrying(mut self, policy: &RetryPolicy) -> HalResult<T>
+    where
+        Self: Sized + Send + 'static,
+        T: Send;
+}
+
+#[async_trait]
+impl<T, Fut, F> RetryableCall<T, Fut> for F
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = HalResult<T>> + Send,
+{
+    async fn retrying(mut self, policy: &RetryPolicy) -> HalResult<T>
+    where
+        Self: Sized + Send + 'static,
+        T: Send,
+    {
+        execute_with_retry(policy, &mut self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::error::HalError;
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(
+            Duration::from_millis(1),
+            1.0,
+            Duration::from_millis(1),
+            max_attempts,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_retry_succeeds_eventually() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let result = execute_with_retry(&fast_policy(5), || {
+            let attempts = attempts.clone();
+            async move {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(HalError::BackendUnavailable("offline".into()))
+                } else {
+                    Ok(42)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_retry_stops_on_non_transient() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let result: HalResult<()> = execute_with_retry(&fast_policy(5), || {
+            let attempts = attempts.clone();
+            async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(HalError::InvalidCircuit("bad".into()))
+            }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_retry_exhausts_max_attempts() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let result: HalResult<()> = execute_with_retry(&fast_policy(3), || {
+            let attempts = attempts.clone();
+            async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(HalError::Timeout("job-1".into()))
+            }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_retry_infinite_keeps_retrying_until_success() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let policy = RetryPolicy::with_backoff(
+            MaxRetries::Infinite,
+            Backoff::Linear { base: Duration::from_millis(1), max: Duration::from_millis(1) },
+        );
+        let result = execute_with_retry(&policy, || {
+            let attempts = attempts.clone();
+            async move {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 10 {
+                    Err(HalError::Timeout("job-1".into()))
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 11);
+    }
+
+    #[test]
+    fn test_delay_for_attempt_truncates_at_max() {
+        let policy = RetryPolicy::new(
+            Duration::from_millis(100),
+            10.0,
+            Duration::from_millis(500),
+            10,
+        );
+        assert_eq!(policy.delay_for_attempt(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for_attempt(3), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_backoff_linear_grows_by_fixed_increment() {
+        let backoff = Backoff::Linear { base: Duration::from_millis(100), max: Duration::from_millis(250) };
+        assert_eq!(backoff.delay_for_attempt(1), Duration::from_millis(100));
+        assert_eq!(backoff.delay_for_attempt(2), Duration::from_millis(200));
+        assert_eq!(backoff.delay_for_attempt(3), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_max_retries_count_allows_up_to_limit() {
+        let max_retries = MaxRetries::Count(2);
+        assert!(max_retries.allows(1));
+        assert!(max_retries.allows(2));
+        assert!(!max_retries.allows(3));
+    }
+
+    #[test]
+    fn test_max_retries_infinite_always_allows() {
+        assert!(MaxRetries::Infinite.allows(1_000_000));
+    }
+
+    #[tokio::test]
+    async fn test_retryable_call_extension() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let policy = fast_policy(5);
+        let call = {
+            let attempts = attempts.clone();
+            move || {
+                let attempts = attempts.clone();
+                async move {
+                    if attempts.fetch_add(1, Ordering::SeqCst) < 1 {
+                        Err(HalError::BackendUnavailable("offline".into()))
+                    } else {
+                        Ok(7)
+                    }
+                }
+            }
+        };
+        assert_eq!(call.retrying(&policy).await.unwrap(), 7);
+    }
+}
@@ -0,0 +1,452 @@
+//! Status-polling helper: `wait → result` in one call, after `submit()`.
+//!
+//! Without this, every caller hand-rolls a loop over `status()` until
+//! [`JobStatus::is_terminal`], forgets the failed/cancelled cases, or
+//! under/over-polls a backend. [`wait_for_terminal`] does that loop once,
+//! fetching `result()` only once the job reaches `Completed`, and surfaces
+//! stuck-queue situations — which `queue_depth` alone can't, since a
+//! backend may simply stop progressing a job — via [`WaitWarning`]
+//! callbacks when a single poll or the cumulative wait runs long.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::error::{HalError, HalResult};
+use crate::job::{JobId, JobStatus};
+use crate::result::ExecutionResult;
+
+/// Interval schedule between polls in [`wait_for_terminal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PollSchedule {
+    /// Poll at a fixed interval.
+    Fixed(Duration),
+    /// Poll with exponential backoff: `initial * multiplier^(n-1)`,
+    /// capped at `max`.
+    Exponential {
+        /// Delay after the first poll, before the second.
+        initial: Duration,
+        /// Multiplier applied after each poll.
+        multiplier: f64,
+        /// Upper bound on any single delay.
+        max: Duration,
+    },
+}
+
+impl PollSchedule {
+    /// Delay after poll `n` (1-indexed), before the next one. The first
+    /// poll itself is not delayed.
+    pub(crate) fn delay_for_attempt(&self, n: u32) -> Duration {
+        match self {
+            Self::Fixed(delay) => *delay,
+            Self::Exponential { initial, multiplier, max } => {
+                let secs = initial.as_secs_f64() * multiplier.powi(n as i32 - 1);
+                Duration::from_secs_f64(secs).min(*max)
+            }
+        }
+    }
+
+    /// Upper bound on any delay this schedule can produce.
+    pub(crate) fn upper_bound(&self) -> Duration {
+        match self {
+            Self::Fixed(delay) => *delay,
+            Self::Exponential { max, .. } => *max,
+        }
+    }
+}
+
+/// Multiply `delay` by a random factor in `[0.5, 1.0]` when `jitter` is
+/// set, to avoid thundering-herd synchronization across many concurrent
+/// waiters polling the same backend.
+pub(crate) fn apply_jitter(delay: Duration, jitter: bool) -> Duration {
+    if jitter {
+        delay.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+    } else {
+        delay
+    }
+}
+
+/// A single poll or the cumulative wait in [`wait_for_terminal`] exceeded
+/// its configured threshold.
+#[derive(Debug, Clone, Copy)]
+pub enum WaitWarning {
+    /// One `status()` call took at least this long.
+    SlowPoll(Duration),
+    /// The job has been pending for at least this long in total.
+    SlowCumulative(Duration),
+}
+
+/// Observability event emitted by
+/// [`Backend::wait_with_observer`](crate::backend::Backend::wait_with_observer)
+/// once per poll iteration, in addition to the result it eventually
+/// returns.
+#[derive(Debug, Clone)]
+pub enum PollEvent {
+    /// A `status()` call returned `status` after `elapsed`, on poll
+    /// number `attempt` (1-indexed).
+    Polled {
+        /// 1-indexed poll number.
+        attempt: u32,
+        /// Status returned by this poll.
+        status: crate::job::JobStatus,
+        /// How long this `status()` call took.
+        elapsed: Duration,
+        /// `BackendAvailability::queue_depth` as of this poll, if the
+        /// backend's `availability()` call succeeded and reported one.
+        queue_depth: Option<u32>,
+    },
+    /// This poll or the cumulative wait crossed a configured
+    /// [`WaitOptions`] threshold.
+    Warning(WaitWarning),
+}
+
+/// Cooperative cancellation switch for [`Backend::wait_with`](crate::backend::Backend::wait_with).
+///
+/// Cloning shares the same underlying flag, so a caller can hold one
+/// clone and hand out others across tasks — e.g. to cancel a whole
+/// fan-out of waits as soon as one of them fails.
+#[derive(Debug, Clone, Default)]
+pub struct CancelSwitch {
+    flag: Arc<AtomicBool>,
+}
+
+impl CancelSwitch {
+    /// Create a switch that has not been triggered yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Signal cancellation. Idempotent; visible to every clone.
+    pub fn trigger(&self) {
+        self.flag.store(true, Ordering::Release);
+    }
+
+    /// Whether `trigger` has been called on this switch or any clone of it.
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Acquire)
+    }
+}
+
+/// Configuration for [`wait_for_terminal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaitOptions {
+    /// Interval schedule between polls.
+    pub schedule: PollSchedule,
+    /// Overall deadline; exceeding it without reaching a terminal state
+    /// returns `HalError::Timeout`.
+    pub timeout: Duration,
+    /// Emit [`WaitWarning::SlowPoll`] once a single poll takes at least
+    /// this long.
+    pub slow_poll_threshold: Duration,
+    /// Emit [`WaitWarning::SlowCumulative`] once the cumulative wait
+    /// reaches at least this long.
+    pub slow_cumulative_threshold: Duration,
+    /// Multiply each computed delay by a random factor in `[0.5, 1.0]`,
+    /// so many concurrent waiters on the same backend don't all poll in
+    /// lockstep.
+    pub jitter: bool,
+}
+
+impl WaitOptions {
+    /// Create options with the default long-poll thresholds (10s / 60s)
+    /// and jitter disabled.
+    pub fn new(schedule: PollSchedule, timeout: Duration) -> Self {
+        Self {
+            schedule,
+            timeout,
+            slow_poll_threshold: Duration::from_secs(10),
+            slow_cumulative_threshold: Duration::from_secs(60),
+            jitter: false,
+        }
+    }
+
+    /// Set the slow-single-poll threshold.
+    pub fn with_slow_poll_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_poll_threshold = threshold;
+        self
+    }
+
+    /// Set the slow-cumulative-wait threshold.
+    pub fn with_slow_cumulative_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_cumulative_threshold = threshold;
+        self
+    }
+
+    /// Enable or disable jitter.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+}
+
+impl Default for WaitOptions {
+    /// 500ms fixed poll, 5-minute timeout — matches `Backend::wait`'s
+    /// previous hardcoded default.
+    fn default() -> Self {
+        Self::new(PollSchedule::Fixed(Duration::from_millis(500)), Duration::from_secs(300))
+    }
+}
+
+/// The one polling loop behind both [`wait_for_terminal`] and
+/// [`Backend::wait_with_observer`](crate::backend::Backend::wait_with_observer).
+///
+/// Polls `status_fn` on `options.schedule` until a terminal [`JobStatus`]
+/// is reached or `options.timeout` elapses, then resolves `result_fn` once
+/// the status is `Completed`. `on_event` is called once per iteration with
+/// `PollEvent::Polled`, plus `PollEvent::Warning` whenever that poll or the
+/// cumulative wait crosses `options`' thresholds (not more than once per
+/// iteration per threshold kind). `first_delay_override`, if set, replaces
+/// `options.schedule`'s own first-attempt delay — used by `wait_with` to
+/// clamp toward a backend's `estimated_wait_secs` hint. `queue_depth_fn` is
+/// polled once per iteration and its result attached to `PollEvent::Polled`
+/// — `wait_with_observer` probes `availability()` there, `wait_for_terminal`
+/// has no such concept and passes a function that always returns `None`.
+///
+/// Callers that need to react mid-loop (cooperative cancellation, say)
+/// fold that into `status_fn` itself: have it check the condition before
+/// calling through to the real status check and return the appropriate
+/// `Err` early. `wait_for_terminal` and `wait_with_observer` both do this
+/// rather than threading extra control-flow parameters through here.
+pub(crate) async fn poll_loop<St, StFut, Rs, RsFut, Qd, QdFut>(
+    job_id: &JobId,
+    options: &WaitOptions,
+    mut status_fn: St,
+    result_fn: Rs,
+    mut on_event: impl FnMut(PollEvent),
+    first_delay_override: Option<Duration>,
+    mut queue_depth_fn: Qd,
+) -> HalResult<ExecutionResult>
+where
+    St: FnMut() -> StFut,
+    StFut: Future<Output = HalResult<JobStatus>>,
+    Rs: FnOnce() -> RsFut,
+    RsFut: Future<Output = HalResult<ExecutionResult>>,
+    Qd: FnMut() -> QdFut,
+    QdFut: Future<Output = Option<u32>>,
+{
+    let start = Instant::now();
+    let mut attempt: u32 = 1;
+
+    loop {
+        let poll_start = Instant::now();
+        let status = status_fn().await?;
+        let poll_elapsed = poll_start.elapsed();
+        let queue_depth = queue_depth_fn().await;
+
+        on_event(PollEvent::Polled { attempt, status: status.clone(), elapsed: poll_elapsed, queue_depth });
+        if poll_elapsed >= options.slow_poll_threshold {
+            on_event(PollEvent::Warning(WaitWarning::SlowPoll(poll_elapsed)));
+        }
+        let cumulative = start.elapsed();
+        if cumulative >= options.slow_cumulative_threshold {
+            on_event(PollEvent::Warning(WaitWarning::SlowCumulative(cumulative)));
+        }
+
+        match status {
+            JobStatus::Completed => return result_fn().await,
+            JobStatus::Failed(msg) => return Err(HalError::JobFailed(msg)),
+            JobStatus::Cancelled => return Err(HalError::JobCancelled),
+            JobStatus::Queued | JobStatus::Running => {
+                if start.elapsed() >= options.timeout {
+                    return Err(HalError::Timeout(job_id.0.clone()));
+                }
+                let base_delay = if attempt == 1 {
+                    first_delay_override.unwrap_or_else(|| options.schedule.delay_for_attempt(attempt))
+                } else {
+                    options.schedule.delay_for_attempt(attempt)
+                };
+                tokio::time::sleep(apply_jitter(base_delay, options.jitter)).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Poll `status_fn` on `options.schedule` until a terminal [`JobStatus`]
+/// is reached or `options.timeout` elapses, then resolve `result_fn` once
+/// the status is `Completed`.
+///
+/// `on_warning` is called synchronously whenever a single poll or the
+/// cumulative wait crosses the configured thresholds; it is not called
+/// more than once per poll iteration per threshold kind. Thin wrapper over
+/// [`poll_loop`], which also backs
+/// [`Backend::wait_with_observer`](crate::backend::Backend::wait_with_observer) —
+/// both share one polling implementation.
+pub async fn wait_for_terminal<St, StFut, Rs, RsFut>(
+    job_id: &JobId,
+    options: &WaitOptions,
+    status_fn: St,
+    result_fn: Rs,
+    mut on_warning: impl FnMut(WaitWarning),
+) -> HalResult<ExecutionResult>
+where
+    St: FnMut() -> StFut,
+    StFut: Future<Output = HalResult<JobStatus>>,
+    Rs: FnOnce() -> RsFut,
+    RsFut: Future<Output = HalResult<ExecutionResult>>,
+{
+    poll_loop(
+        job_id,
+        options,
+        status_fn,
+        result_fn,
+        |event| {
+            if let PollEvent::Warning(warning) = event {
+                on_warning(warning);
+            }
+        },
+        None,
+        || async { None },
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+
+    fn fast_options() -> WaitOptions {
+        WaitOptions::new(PollSchedule::Fixed(Duration::from_millis(1)), Duration::from_secs(5))
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_terminal_returns_result_on_completion() {
+        let polls = Arc::new(AtomicU32::new(0));
+        let job_id = JobId::new("job-1");
+
+        let result = wait_for_terminal(
+            &job_id,
+            &fast_options(),
+            || {
+                let polls = polls.clone();
+                async move {
+                    if polls.fetch_add(1, Ordering::SeqCst) < 2 {
+                        Ok(JobStatus::Running)
+                    } else {
+                        Ok(JobStatus::Completed)
+                    }
+                }
+            },
+            || async { Ok(ExecutionResult::new(crate::result::Counts::new(), 10)) },
+            |_| {},
+        )
+        .await;
+
+        assert_eq!(result.unwrap().shots, 10);
+        assert_eq!(polls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_terminal_propagates_failed() {
+        let job_id = JobId::new("job-1");
+        let result = wait_for_terminal(
+            &job_id,
+            &fast_options(),
+            || async { Ok(JobStatus::Failed("boom".into())) },
+            || async { unreachable!("result() should not be called on failure") },
+            |_| {},
+        )
+        .await;
+
+        assert!(matches!(result, Err(HalError::JobFailed(msg)) if msg == "boom"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_terminal_propagates_cancelled() {
+        let job_id = JobId::new("job-1");
+        let result = wait_for_terminal(
+            &job_id,
+            &fast_options(),
+            || async { Ok(JobStatus::Cancelled) },
+            || async { unreachable!("result() should not be called on cancellation") },
+            |_| {},
+        )
+        .await;
+
+        assert!(matches!(result, Err(HalError::JobCancelled)));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_terminal_times_out() {
+        let job_id = JobId::new("job-1");
+        let options = WaitOptions::new(PollSchedule::Fixed(Duration::from_millis(1)), Duration::from_millis(5));
+
+        let result = wait_for_terminal(
+            &job_id,
+            &options,
+            || async { Ok(JobStatus::Running) },
+            || async { unreachable!("result() should not be called on timeout") },
+            |_| {},
+        )
+        .await;
+
+        assert!(matches!(result, Err(HalError::Timeout(id)) if id == "job-1"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_terminal_emits_slow_poll_warning() {
+        let job_id = JobId::new("job-1");
+        let options = fast_options().with_slow_poll_threshold(Duration::ZERO);
+        let warnings = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let _ = wait_for_terminal(
+            &job_id,
+            &options,
+            || async { Ok(JobStatus::Completed) },
+            || async { Ok(ExecutionResult::default()) },
+            |warning| warnings.lock().unwrap().push(warning),
+        )
+        .await;
+
+        assert!(matches!(
+            warnings.lock().unwrap().as_slice(),
+            [WaitWarning::SlowPoll(_), ..]
+        ));
+    }
+
+    #[test]
+    fn test_apply_jitter_disabled_is_identity() {
+        let delay = Duration::from_millis(100);
+        assert_eq!(apply_jitter(delay, false), delay);
+    }
+
+    #[test]
+    fn test_apply_jitter_enabled_stays_in_half_to_full_range() {
+        let delay = Duration::from_millis(100);
+        for _ in 0..50 {
+            let jittered = apply_jitter(delay, true);
+            assert!(jittered >= Duration::from_millis(50));
+            assert!(jittered <= delay);
+        }
+    }
+
+    #[test]
+    fn test_cancel_switch_starts_uncancelled() {
+        assert!(!CancelSwitch::new().is_cancelled());
+    }
+
+    #[test]
+    fn test_cancel_switch_clone_shares_flag() {
+        let switch = CancelSwitch::new();
+        let clone = switch.clone();
+        clone.trigger();
+        assert!(switch.is_cancelled());
+    }
+
+    #[test]
+    fn test_poll_schedule_exponential_caps_at_max() {
+        let schedule = PollSchedule::Exponential {
+            initial: Duration::from_millis(100),
+            multiplier: 10.0,
+            max: Duration::from_millis(500),
+        };
+        assert_eq!(schedule.delay_for_attempt(1), Duration::from_millis(100));
+        assert_eq!(schedule.delay_for_attempt(3), Duration::from_millis(500));
+    }
+}